@@ -0,0 +1,102 @@
+use std::{collections::BTreeMap, fmt, fs, io, path::Path};
+
+/// Stable 64-bit FNV-1a hash of a puzzle input, in hex.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Known answers keyed by day, input hash and part.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(pub BTreeMap<(u8, String, u8), String>);
+
+impl Answers {
+    /// Loads the answers file, treating a missing file as empty.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => text
+                .parse()
+                .map_err(|err: String| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, hash: &str, part: u8) -> Option<&str> {
+        self.0
+            .get(&(day, hash.to_owned(), part))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, hash: &str, part: u8, answer: String) {
+        self.0.insert((day, hash.to_owned(), part), answer);
+    }
+}
+
+/// One `day hash part answer` line per answer.
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ((day, hash, part), answer) in &self.0 {
+            writeln!(f, "{}\t{}\t{}\t{}", day, hash, part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut res = BTreeMap::new();
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let invalid = || format!("line {}: invalid answer {:?}", i + 1, line);
+            let fields: Vec<&str> = line.splitn(4, '\t').collect();
+            let [day, hash, part, answer] = fields.as_slice() else {
+                return Err(invalid());
+            };
+            res.insert(
+                (
+                    day.parse().map_err(|_| invalid())?,
+                    hash.to_string(),
+                    part.parse().map_err(|_| invalid())?,
+                ),
+                answer.to_string(),
+            );
+        }
+        Ok(Answers(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("L68\n"), input_hash("L68"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(11, "00ff", 2, "2".to_owned());
+        answers.insert(1, "00ff", 1, "3".to_owned());
+
+        let text = answers.to_string();
+        assert_eq!(text, "1\t00ff\t1\t3\n11\t00ff\t2\t2\n");
+        assert_eq!(text.parse(), Ok(answers));
+        assert!("1\t00ff\t1".parse::<Answers>().is_err());
+        assert!("x\t00ff\t1\t3".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    collections::HashMap,
+    fmt, fs, io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use common::{ParseError, Solution};
+
+/// The stages of a day that are timed separately.
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+/// Timings of one stage, over all samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        Self {
+            median,
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Times parsing and both parts of `S` on `input`, returning one entry per
+/// stage in [`STAGES`] order.
+pub fn bench<S: Solution>(
+    input: &str,
+    params: &S::Params,
+    options: Options,
+) -> Result<Vec<Stats>, ParseError> {
+    let parsed = S::parse(input)?;
+
+    let parse = time(options, || {
+        std::hint::black_box(S::parse(input).ok());
+    });
+    let part1 = time(options, || {
+        std::hint::black_box(S::part1(&parsed, params));
+    });
+    let part2 = time(options, || {
+        std::hint::black_box(S::part2(&parsed, params));
+    });
+
+    Ok(vec![parse, part1, part2])
+}
+
+fn time(options: Options, mut f: impl FnMut()) -> Stats {
+    for _ in 0..options.warmup {
+        f();
+    }
+    let mut samples: Vec<Duration> = (0..options.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+    Stats::new(&mut samples)
+}
+
+/// Saved results keyed by day and stage name.
+#[derive(Debug, Default, PartialEq)]
+pub struct Results(pub HashMap<(u8, String), Stats>);
+
+impl Results {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        fs::read_to_string(path)?
+            .parse()
+            .map_err(|err: String| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+}
+
+/// One `day stage median min max` line per stage, durations in nanoseconds.
+impl fmt::Display for Results {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut keys: Vec<_> = self.0.keys().collect();
+        keys.sort();
+        for key in keys {
+            let stats = &self.0[key];
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}",
+                key.0,
+                key.1,
+                stats.median.as_nanos(),
+                stats.min.as_nanos(),
+                stats.max.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Results {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut res = HashMap::new();
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let invalid = || format!("line {}: invalid benchmark result {:?}", i + 1, line);
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [day, stage, median, min, max] = fields.as_slice() else {
+                return Err(invalid());
+            };
+            let nanos = |s: &str| s.parse().map(Duration::from_nanos).map_err(|_| invalid());
+            res.insert(
+                (day.parse().map_err(|_| invalid())?, stage.to_string()),
+                Stats {
+                    median: nanos(median)?,
+                    min: nanos(min)?,
+                    max: nanos(max)?,
+                },
+            );
+        }
+        Ok(Results(res))
+    }
+}
+
+/// Relative change of the median against the baseline, in percent.
+pub fn change(stats: &Stats, baseline: &Stats) -> f64 {
+    let base = baseline.median.as_secs_f64();
+    if base == 0.0 {
+        return 0.0;
+    }
+    (stats.median.as_secs_f64() - base) / base * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(
+            Stats::new(&mut [ms(5), ms(1), ms(3)]),
+            Stats {
+                median: ms(3),
+                min: ms(1),
+                max: ms(5)
+            }
+        );
+        assert_eq!(Stats::new(&mut [ms(4), ms(2), ms(1), ms(8)]).median, ms(3));
+    }
+
+    #[test]
+    fn test_results_round_trip() {
+        let mut results = Results::default();
+        let stats = Stats {
+            median: ms(3),
+            min: ms(1),
+            max: ms(5),
+        };
+        results.0.insert((7, "part1".to_owned()), stats);
+        results.0.insert((10, "parse".to_owned()), stats);
+
+        let text = results.to_string();
+        assert_eq!(
+            text,
+            "7\tpart1\t3000000\t1000000\t5000000\n10\tparse\t3000000\t1000000\t5000000\n"
+        );
+        assert_eq!(text.parse(), Ok(results));
+        assert!("7\tpart1\t3".parse::<Results>().is_err());
+    }
+
+    #[test]
+    fn test_change() {
+        let stats = |median| Stats {
+            median,
+            min: median,
+            max: median,
+        };
+        assert_eq!(change(&stats(ms(15)), &stats(ms(10))).round(), 50.0);
+        assert_eq!(change(&stats(ms(5)), &stats(ms(10))).round(), -50.0);
+        assert_eq!(change(&stats(ms(5)), &stats(ms(0))), 0.0);
+    }
+}
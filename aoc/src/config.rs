@@ -0,0 +1,180 @@
+use std::{collections::BTreeMap, fs, io, path::Path, time::Duration};
+
+/// Parameter overrides and time limits per day, read from a file like
+///
+/// ```toml
+/// timeout = 60
+///
+/// [day8]
+/// connections = 1000
+/// count = 3
+/// timeout = 5
+/// ```
+///
+/// Timeouts are in seconds and apply to each part, the one of a day winning
+/// over the one for all days.
+#[derive(Debug, Default, PartialEq)]
+pub struct Config {
+    params: BTreeMap<u8, Vec<(String, String)>>,
+    timeout: Option<Duration>,
+    timeouts: BTreeMap<u8, Duration>,
+}
+
+impl Config {
+    /// Reads `path`, or `aoc.toml` if it exists when no path is given.
+    pub fn load(path: Option<&Path>) -> Result<Self, String> {
+        let (path, required) = match path {
+            Some(path) => (path, true),
+            None => (Path::new("aoc.toml"), false),
+        };
+        match fs::read_to_string(path) {
+            Ok(text) => text
+                .parse()
+                .map_err(|err| format!("{}: {}", path.display(), err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound && !required => Ok(Self::default()),
+            Err(err) => Err(format!("{}: {}", path.display(), err)),
+        }
+    }
+
+    /// The parameters of `day` from the file followed by `overrides`, so the
+    /// latter win when applied in order.
+    pub fn params(&self, day: u8, overrides: &[(String, String)]) -> Vec<(String, String)> {
+        let mut res = self.params.get(&day).cloned().unwrap_or_default();
+        res.extend_from_slice(overrides);
+        res
+    }
+
+    /// Time limit for each part of `day`, if any.
+    pub fn timeout(&self, day: u8) -> Option<Duration> {
+        self.timeouts.get(&day).copied().or(self.timeout)
+    }
+
+    /// Replaces every timeout of the file with `timeout`, as given on the
+    /// command line.
+    pub fn override_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+        self.timeouts.clear();
+    }
+}
+
+impl std::str::FromStr for Config {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table: toml::Table = s.parse().map_err(|err: toml::de::Error| err.to_string())?;
+        let mut res = Config::default();
+
+        for (key, value) in table {
+            if key == "timeout" {
+                res.timeout = Some(seconds(&key, &value)?);
+                continue;
+            }
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("expected a [dayN] table, got {:?}", key))?;
+            let toml::Value::Table(mut params) = value else {
+                return Err(format!("expected [{}] to be a table", key));
+            };
+
+            if let Some(timeout) = params.remove("timeout") {
+                res.timeouts
+                    .insert(day, seconds(&format!("{}.timeout", key), &timeout)?);
+            }
+            let params = params
+                .into_iter()
+                .map(|(name, value)| match value {
+                    toml::Value::String(s) => Ok((name, s)),
+                    toml::Value::Integer(_) | toml::Value::Float(_) | toml::Value::Boolean(_) => {
+                        Ok((name, value.to_string()))
+                    }
+                    _ => Err(format!("{}.{}: expected a number or string", key, name)),
+                })
+                .collect::<Result<_, _>>()?;
+            res.params.insert(day, params);
+        }
+
+        Ok(res)
+    }
+}
+
+fn seconds(key: &str, value: &toml::Value) -> Result<Duration, String> {
+    let seconds = match *value {
+        toml::Value::Integer(n) => n as f64,
+        toml::Value::Float(x) => x,
+        _ => f64::NAN,
+    };
+    parse_seconds(&seconds.to_string()).map_err(|err| format!("{}: {}", key, err))
+}
+
+/// Parses a positive number of seconds.
+pub fn parse_seconds(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>()
+        .ok()
+        .filter(|&seconds| seconds > 0.0)
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| format!("expected a positive number of seconds, got {:?}", s))
+}
+
+/// Parses a `name=value` command line parameter.
+pub fn parse_param(s: &str) -> Result<(String, String), String> {
+    s.split_once('=')
+        .map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned()))
+        .ok_or_else(|| format!("expected name=value, got {:?}", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn param(name: &str, value: &str) -> (String, String) {
+        (name.to_owned(), value.to_owned())
+    }
+
+    #[test]
+    fn test_parse() {
+        let config: Config = "[day8]\nconnections = 10\ncount = 3\n\n[day11]\nyou = \"me\"\n"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            config.params(8, &[param("count", "2")]),
+            vec![
+                param("connections", "10"),
+                param("count", "3"),
+                param("count", "2")
+            ]
+        );
+        assert_eq!(config.params(11, &[]), vec![param("you", "me")]);
+        assert_eq!(config.params(1, &[]), vec![]);
+    }
+
+    #[test]
+    fn test_timeouts() {
+        let mut config: Config = "timeout = 60\n[day8]\ncount = 3\ntimeout = 0.5\n"
+            .parse()
+            .unwrap();
+        assert_eq!(config.params(8, &[]), vec![param("count", "3")]);
+        assert_eq!(config.timeout(8), Some(Duration::from_millis(500)));
+        assert_eq!(config.timeout(1), Some(Duration::from_secs(60)));
+
+        config.override_timeout(Duration::from_secs(2));
+        assert_eq!(config.timeout(8), Some(Duration::from_secs(2)));
+        assert_eq!(Config::default().timeout(1), None);
+
+        assert!("timeout = 0".parse::<Config>().is_err());
+        assert!("[day1]\ntimeout = \"soon\"".parse::<Config>().is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!("[days]\nx = 1".parse::<Config>().is_err());
+        assert!("day1 = 1".parse::<Config>().is_err());
+        assert!("[day1]\nx = [1]".parse::<Config>().is_err());
+    }
+
+    #[test]
+    fn test_parse_param() {
+        assert_eq!(parse_param("count=3"), Ok(param("count", "3")));
+        assert!(parse_param("count").is_err());
+    }
+}
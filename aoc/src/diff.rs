@@ -0,0 +1,280 @@
+use std::fmt;
+
+use common::{Reference, Rng};
+
+use crate::isolate::{catch, quietly};
+
+/// Largest size of the random inputs, small enough for the naive solutions.
+const MAX_SIZE: u64 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    pub seed: u64,
+    pub cases: usize,
+}
+
+/// What a solver returned, or the message it panicked with.
+pub type Outcome = Result<String, String>;
+
+/// A random input on which the real and naive solvers give different answers.
+#[derive(Debug, PartialEq)]
+pub struct Disagreement {
+    /// Seed that generates the original input.
+    pub seed: u64,
+    pub part: u8,
+    /// Smallest input found that still disagrees.
+    pub input: String,
+    pub lines: usize,
+    pub real: Outcome,
+    pub naive: Outcome,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |outcome: &Outcome| match outcome {
+            Ok(answer) => answer.clone(),
+            Err(message) => format!("panicked: {}", message),
+        };
+        writeln!(
+            f,
+            "part {} disagrees on seed {}, minimized from {} to {} lines:",
+            self.part,
+            self.seed,
+            self.lines,
+            self.input.lines().count()
+        )?;
+        write!(f, "{}", self.input)?;
+        if !self.input.ends_with('\n') {
+            writeln!(f)?;
+        }
+        writeln!(f, "real:  {}", show(&self.real))?;
+        write!(f, "naive: {}", show(&self.naive))
+    }
+}
+
+/// Runs the real and naive solvers of `S` on `options.cases` random inputs,
+/// returning the first disagreement after shrinking it. Case `i` is generated
+/// from seed `options.seed + i`, at a size picked by the same seed.
+pub fn diff<S: Reference>(
+    params: &S::Params,
+    options: Options,
+) -> Result<Option<Disagreement>, String> {
+    quietly(|| run::<S>(params, options))
+}
+
+fn run<S: Reference>(params: &S::Params, options: Options) -> Result<Option<Disagreement>, String> {
+    for i in 0..options.cases as u64 {
+        let seed = options.seed.wrapping_add(i);
+        let input = random_input::<S>(seed, params);
+        if let Err(err) = S::parse(&input) {
+            return Err(format!(
+                "random input for seed {} doesn't parse: {}",
+                seed, err
+            ));
+        }
+        if !S::is_valid(&input, params) {
+            return Err(format!(
+                "random input for seed {} isn't valid:\n{}",
+                seed, input
+            ));
+        }
+
+        for part in [1, 2] {
+            let (real, naive) = solve::<S>(&input, part, params);
+            if real != naive {
+                let input = shrink::<S>(input.clone(), part, params, (&real, &naive));
+                let (real, naive) = solve::<S>(&input, part, params);
+                return Ok(Some(Disagreement {
+                    seed,
+                    part,
+                    lines: random_input::<S>(seed, params).lines().count(),
+                    input,
+                    real,
+                    naive,
+                }));
+            }
+        }
+    }
+    Ok(None)
+}
+
+fn random_input<S: Reference>(seed: u64, params: &S::Params) -> String {
+    let mut rng = Rng::new(seed);
+    let size = rng.range(1..=MAX_SIZE) as usize;
+    S::generate(&mut rng, size, params)
+}
+
+/// Answers of the real and naive solvers for `part`, catching panics.
+fn solve<S: Reference>(input: &str, part: u8, params: &S::Params) -> (Outcome, Outcome) {
+    let real = catch(|| {
+        let parsed = S::parse(input).unwrap();
+        match part {
+            1 => S::part1(&parsed, params).to_string(),
+            _ => S::part2(&parsed, params).to_string(),
+        }
+    });
+    let naive = catch(|| match part {
+        1 => S::naive1(input, params).to_string(),
+        _ => S::naive2(input, params).to_string(),
+    });
+    (real, naive)
+}
+
+/// Most inputs tried while shrinking, to bound slow solvers.
+const SHRINK_BUDGET: usize = 2000;
+
+/// Greedily replaces `input` with smaller variants that still parse, stay
+/// valid and disagree the same way: both solvers answering, or the same one
+/// panicking.
+fn shrink<S: Reference>(
+    mut input: String,
+    part: u8,
+    params: &S::Params,
+    (real, naive): (&Outcome, &Outcome),
+) -> String {
+    let kind = (real.is_ok(), naive.is_ok());
+    let mut tries = 0;
+
+    'outer: loop {
+        for candidate in candidates(&input) {
+            tries += 1;
+            if tries > SHRINK_BUDGET {
+                break 'outer;
+            }
+            if S::parse(&candidate).is_err() || !S::is_valid(&candidate, params) {
+                continue;
+            }
+            let (real, naive) = solve::<S>(&candidate, part, params);
+            if real != naive && (real.is_ok(), naive.is_ok()) == kind {
+                input = candidate;
+                continue 'outer;
+            }
+        }
+        break;
+    }
+    input
+}
+
+/// Smaller variants of `input`: without a line, without an item of a comma or
+/// space separated line, or with a smaller number.
+fn candidates(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let join = |lines: &[String]| lines.iter().map(|line| format!("{}\n", line)).collect();
+    let mut res = Vec::new();
+
+    for i in 0..lines.len() {
+        let mut shorter: Vec<String> = lines.iter().map(|s| s.to_string()).collect();
+        shorter.remove(i);
+        res.push(join(&shorter));
+    }
+
+    for (i, line) in lines.iter().enumerate() {
+        for separator in [",", " "] {
+            let items: Vec<&str> = line.split(separator).collect();
+            if items.len() < 2 {
+                continue;
+            }
+            for j in 0..items.len() {
+                let mut fewer = items.clone();
+                fewer.remove(j);
+                let mut changed: Vec<String> = lines.iter().map(|s| s.to_string()).collect();
+                changed[i] = fewer.join(separator);
+                res.push(join(&changed));
+            }
+        }
+    }
+
+    let mut start = None;
+    for (i, c) in input.char_indices().chain([(input.len(), ' ')]) {
+        match (start, c.is_ascii_digit()) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                if let Ok(n) = input[s..i].parse::<u64>() {
+                    let mut smaller = vec![0, n / 2, n.saturating_sub(1)];
+                    smaller.dedup();
+                    for m in smaller.into_iter().filter(|&m| m < n) {
+                        res.push(format!("{}{}{}", &input[..s], m, &input[i..]));
+                    }
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{Generator, ParseError, Solution};
+
+    use super::*;
+
+    /// Sums numbers, with a real part 1 that ignores numbers over 9.
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input<'a> = Vec<u64>;
+        type Answer1 = u64;
+        type Answer2 = u64;
+        type Params = ();
+
+        fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+            common::lines(0, input)
+                .map(|line| line.parse(line.text, "number"))
+                .collect()
+        }
+
+        fn part1(nums: &Vec<u64>, _: &()) -> u64 {
+            nums.iter().filter(|&&n| n <= 9).sum()
+        }
+
+        fn part2(nums: &Vec<u64>, _: &()) -> u64 {
+            nums.iter().sum()
+        }
+    }
+
+    impl Reference for Sum {
+        fn naive1(input: &str, _: &()) -> u64 {
+            input.lines().map(|line| line.parse::<u64>().unwrap()).sum()
+        }
+
+        fn naive2(input: &str, params: &()) -> u64 {
+            Self::naive1(input, params)
+        }
+    }
+
+    impl Generator for Sum {
+        fn generate(rng: &mut Rng, size: usize, _: &()) -> String {
+            (0..size)
+                .map(|_| format!("{}\n", rng.range(0..=30)))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let found = diff::<Sum>(&(), Options { seed: 1, cases: 20 })
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.part, 1);
+        assert_eq!(found.lines, 2);
+        assert_eq!(found.input, "10\n");
+        assert_eq!(found.real, Ok("0".to_owned()));
+        assert_eq!(found.naive, Ok("10".to_owned()));
+    }
+
+    #[test]
+    fn test_candidates() {
+        assert_eq!(
+            candidates("1-4,5\n"),
+            vec![
+                "", "5\n", "1-4\n", "0-4,5\n", "1-0,5\n", "1-2,5\n", "1-3,5\n", "1-4,0\n",
+                "1-4,2\n", "1-4,4\n"
+            ]
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use std::{fs, io, path::Path};
+
+use common::examples::{Case, parse_expected};
+
+use crate::Solver;
+
+/// An example input with its expected answers, read from `NAME.txt` and
+/// `NAME.expected` in a day's `examples` directory.
+#[derive(Debug)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub cases: Vec<Case>,
+}
+
+/// Reads every example in `dir` that has an expected answers file, sorted by
+/// name.
+pub fn discover(dir: &Path) -> io::Result<Vec<Example>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "expected") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            let invalid = |err| io::Error::new(io::ErrorKind::InvalidData, err);
+            let cases = parse_expected(&fs::read_to_string(&path)?)
+                .map_err(|err| invalid(format!("{}: {}", path.display(), err)))?;
+            let input = fs::read_to_string(path.with_extension("txt"))?;
+            Ok(Example { name, input, cases })
+        })
+        .collect()
+}
+
+/// The answer of one case, or the error that prevented it.
+pub struct Outcome<'a> {
+    pub example: &'a Example,
+    pub case: &'a Case,
+    pub actual: Result<String, String>,
+}
+
+impl Outcome<'_> {
+    pub fn passed(&self) -> bool {
+        self.actual.as_ref() == Ok(&self.case.answer)
+    }
+}
+
+/// Solves every case of `examples` with `solve`.
+pub fn check<'a>(solve: Solver, examples: &'a [Example]) -> Vec<Outcome<'a>> {
+    let mut res = Vec::new();
+    for example in examples {
+        for case in &example.cases {
+            let actual = solve(&example.input, &[case.part], &case.params, None)
+                .map(|mut answers| answers.remove(0).value)
+                .map_err(|err| err.report(&example.input));
+            res.push(Outcome {
+                example,
+                case,
+                actual,
+            });
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DAYS;
+
+    #[test]
+    fn test_examples() {
+        let mut failures = Vec::new();
+        for (day, entry) in (1..).zip(&DAYS) {
+            let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../day{}/examples", day));
+            let examples = discover(&dir).unwrap();
+            assert!(!examples.is_empty(), "day {} has no examples", day);
+
+            for (solver, solve) in [("real", entry.solve), ("naive", entry.naive)] {
+                for outcome in check(solve, &examples) {
+                    if !outcome.passed() {
+                        failures.push(format!(
+                            "day {} {} {} {:?}: got {:?}",
+                            day, solver, outcome.example.name, outcome.case, outcome.actual
+                        ));
+                    }
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
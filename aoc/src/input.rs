@@ -0,0 +1,69 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+/// Where puzzle inputs are read from.
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    /// Every file in the directory, one run per file.
+    Dir(PathBuf),
+}
+
+impl Source {
+    /// Picks the source from the command line, falling back to `dayN/input.txt`.
+    pub fn new(day: u8, input: Option<PathBuf>, input_dir: Option<PathBuf>) -> Self {
+        match (input, input_dir) {
+            (_, Some(dir)) => Source::Dir(dir),
+            (Some(path), None) if path.as_os_str() == "-" => Source::Stdin,
+            (Some(path), None) => Source::File(path),
+            (None, None) => Source::File(PathBuf::from(format!("day{}/input.txt", day))),
+        }
+    }
+
+    /// Reads all inputs as `(label, contents)` pairs, sorted by label for
+    /// directories.
+    pub fn read(&self) -> io::Result<Vec<(String, String)>> {
+        match self {
+            Source::File(path) => Ok(vec![(
+                path.display().to_string(),
+                fs::read_to_string(path).map_err(|err| with_path(err, path))?,
+            )]),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(vec![("-".to_owned(), input)])
+            }
+            Source::Dir(dir) => {
+                let mut paths = Vec::new();
+                for entry in fs::read_dir(dir).map_err(|err| with_path(err, dir))? {
+                    let path = entry?.path();
+                    if path.is_file() {
+                        paths.push(path);
+                    }
+                }
+                paths.sort();
+
+                paths
+                    .into_iter()
+                    .map(|path| {
+                        let label = path.file_name().unwrap().to_string_lossy().into_owned();
+                        let input =
+                            fs::read_to_string(&path).map_err(|err| with_path(err, &path))?;
+                        Ok((label, input))
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    pub fn is_batch(&self) -> bool {
+        matches!(self, Source::Dir(_))
+    }
+}
+
+fn with_path(err: io::Error, path: &std::path::Path) -> io::Error {
+    io::Error::new(err.kind(), format!("{}: {}", path.display(), err))
+}
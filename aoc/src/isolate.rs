@@ -0,0 +1,72 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use common::cancel::{self, Cancelled};
+
+/// Runs `f` with the panic hook silenced, so panics caught inside it don't
+/// print their messages.
+pub fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let res = f();
+    panic::set_hook(hook);
+    res
+}
+
+/// What `f` returned, or the message it panicked with.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| message(&*payload))
+}
+
+/// What `f` returned, or `None` if it was cancelled at a checkpoint after
+/// `timeout`. Other panics carry on unwinding.
+pub fn with_timeout<T>(timeout: Option<Duration>, f: impl FnOnce() -> T) -> Option<T> {
+    match panic::catch_unwind(AssertUnwindSafe(|| cancel::with_timeout(timeout, f))) {
+        Ok(res) => Some(res),
+        Err(payload) if payload.is::<Cancelled>() => None,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(catch(|| "1".to_owned()), Ok("1".to_owned()));
+        assert_eq!(
+            quietly(|| catch(|| -> u8 { panic!("no {}", "path") })),
+            Err("no path".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_with_timeout() {
+        let timeout = Some(Duration::from_millis(5));
+        assert_eq!(with_timeout(timeout, || 1), Some(1));
+        let spin = || {
+            loop {
+                cancel::checkpoint();
+            }
+        };
+        assert_eq!(with_timeout::<()>(timeout, spin), None);
+        assert_eq!(
+            quietly(|| catch(|| with_timeout(timeout, || -> u8 { panic!("other") }))),
+            Err("other".to_owned())
+        );
+    }
+}
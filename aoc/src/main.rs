@@ -0,0 +1,1064 @@
+use std::{
+    path::PathBuf,
+    process,
+    time::{Duration, Instant},
+};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use common::{Generator, Params, ParseError, Reference, Rng, Solution};
+
+use crate::{
+    answers::{Answers, input_hash},
+    bench::{Options, Results, Stats},
+    config::Config,
+    diff::Disagreement,
+    input::Source,
+    table::Table,
+};
+
+mod answers;
+mod bench;
+mod config;
+mod diff;
+mod examples;
+mod input;
+mod isolate;
+mod pool;
+mod scale;
+mod table;
+
+type Solver = fn(&str, &[u8], &[(String, String)], Option<Duration>) -> Result<Vec<Answer>, Error>;
+type Bencher = fn(&str, &[(String, String)], Options) -> Result<Vec<Stats>, Error>;
+type Differ = fn(&[(String, String)], diff::Options) -> Result<Option<Disagreement>, Error>;
+type Generate = fn(u64, usize, &[(String, String)]) -> Result<String, Error>;
+type Scaler = fn(&[(String, String)], scale::Options) -> Result<Vec<scale::Point>, Error>;
+
+/// Type-erased entry points of one day.
+struct Day {
+    solve: Solver,
+    /// Like `solve` with the naive reference solutions.
+    naive: Solver,
+    bench: Bencher,
+    diff: Differ,
+    /// A random input from a seed and a size.
+    generate: Generate,
+    /// Timings over generated inputs of growing sizes.
+    scale: Scaler,
+}
+
+/// One part's answer and how long it took, not counting parsing.
+struct Answer {
+    part: u8,
+    /// "timed out" for parts cancelled after their timeout.
+    value: String,
+    elapsed: Duration,
+    timed_out: bool,
+}
+
+impl Answer {
+    /// Runs one part, cancelling it at its next checkpoint after `timeout`.
+    fn new(part: u8, timeout: Option<Duration>, f: impl FnOnce() -> String) -> Self {
+        let start = Instant::now();
+        let value = isolate::with_timeout(timeout, f);
+        Answer {
+            part,
+            elapsed: start.elapsed(),
+            timed_out: value.is_none(),
+            value: value.unwrap_or_else(|| "timed out".to_owned()),
+        }
+    }
+}
+
+/// Why a day couldn't be solved.
+#[derive(Debug)]
+enum Error {
+    Params(String),
+    Parse(ParseError),
+    /// A random input that breaks the day's own rules.
+    Generate(String),
+}
+
+impl Error {
+    fn report(&self, input: &str) -> String {
+        match self {
+            Error::Params(err) | Error::Generate(err) => err.clone(),
+            Error::Parse(err) => err.report(input),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+const fn day<S: Reference>() -> Day {
+    Day {
+        solve: solve::<S>,
+        naive: naive::<S>,
+        bench: bench::<S>,
+        diff: diff::<S>,
+        generate: generate::<S>,
+        scale: scale::<S>,
+    }
+}
+
+const DAYS: [Day; 11] = [
+    day::<day1::Day1>(),
+    day::<day2::Day2>(),
+    day::<day3::Day3>(),
+    day::<day4::Day4>(),
+    day::<day5::Day5>(),
+    day::<day6::Day6>(),
+    day::<day7::Day7>(),
+    day::<day8::Day8>(),
+    day::<day9::Day9>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+];
+
+/// The day's default parameters with the named `params` applied in order.
+fn params<S: Solution>(params: &[(String, String)]) -> Result<S::Params, Error> {
+    let mut values = S::Params::default();
+    for (name, value) in params {
+        values
+            .set(name, value)
+            .map_err(|err| Error::Params(format!("day {}: {}", S::DAY, err)))?;
+    }
+    Ok(values)
+}
+
+/// Checks the parameters against the parsed input for each of `parts`.
+fn check_params<S: Solution>(
+    parsed: &S::Input<'_>,
+    values: &S::Params,
+    parts: &[u8],
+) -> Result<(), Error> {
+    for &part in parts {
+        S::check(parsed, values, part)
+            .map_err(|err| Error::Params(format!("day {}: {}", S::DAY, err)))?;
+    }
+    Ok(())
+}
+
+/// Parses the input once and returns the answers for `parts` in order, each
+/// part limited to `timeout`.
+fn solve<S: Solution>(
+    input: &str,
+    parts: &[u8],
+    params: &[(String, String)],
+    timeout: Option<Duration>,
+) -> Result<Vec<Answer>, Error> {
+    let values = self::params::<S>(params)?;
+    let parsed = S::parse(input)?;
+    check_params::<S>(&parsed, &values, parts)?;
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            Answer::new(part, timeout, || match part {
+                1 => S::part1(&parsed, &values).to_string(),
+                2 => S::part2(&parsed, &values).to_string(),
+                _ => panic!("invalid part {}", part),
+            })
+        })
+        .collect())
+}
+
+fn naive<S: Reference>(
+    input: &str,
+    parts: &[u8],
+    params: &[(String, String)],
+    timeout: Option<Duration>,
+) -> Result<Vec<Answer>, Error> {
+    let values = self::params::<S>(params)?;
+    check_params::<S>(&S::parse(input)?, &values, parts)?;
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            Answer::new(part, timeout, || match part {
+                1 => S::naive1(input, &values).to_string(),
+                2 => S::naive2(input, &values).to_string(),
+                _ => panic!("invalid part {}", part),
+            })
+        })
+        .collect())
+}
+
+fn diff<S: Reference>(
+    params: &[(String, String)],
+    options: diff::Options,
+) -> Result<Option<Disagreement>, Error> {
+    let values = self::params::<S>(params)?;
+    diff::diff::<S>(&values, options)
+        .map_err(|err| Error::Generate(format!("day {}: {}", S::DAY, err)))
+}
+
+fn generate<S: Generator>(
+    seed: u64,
+    size: usize,
+    params: &[(String, String)],
+) -> Result<String, Error> {
+    let values = self::params::<S>(params)?;
+    Ok(S::generate(&mut Rng::new(seed), size, &values))
+}
+
+fn scale<S: Generator>(
+    params: &[(String, String)],
+    options: scale::Options,
+) -> Result<Vec<scale::Point>, Error> {
+    let values = self::params::<S>(params)?;
+    Ok(scale::profile::<S>(&values, options)?)
+}
+
+fn bench<S: Solution>(
+    input: &str,
+    params: &[(String, String)],
+    options: Options,
+) -> Result<Vec<Stats>, Error> {
+    let values = self::params::<S>(params)?;
+    check_params::<S>(&S::parse(input)?, &values, &[1, 2])?;
+    Ok(bench::bench::<S>(input, &values, options)?)
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    Text,
+    /// One JSON object per answer and line
+    Json,
+}
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2025 solutions runner")]
+struct Cli {
+    /// Parameter overrides per day [default: aoc.toml if present]
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Give up on a part after this many seconds, overriding the config file
+    #[arg(long, global = true, value_name = "SECONDS", value_parser = config::parse_seconds)]
+    timeout: Option<Duration>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day on its puzzle input
+    Run {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+        day: u8,
+
+        /// Run only this part (both parts by default)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file, or "-" for stdin [default: dayN/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Run once per file in this directory and print a table
+        #[arg(long, conflicts_with = "input")]
+        input_dir: Option<PathBuf>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Set a parameter of the day, overriding the config file
+        #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = config::parse_param)]
+        params: Vec<(String, String)>,
+
+        /// Use the slow reference solutions
+        #[arg(long)]
+        naive: bool,
+    },
+    /// Time parsing and each part on the puzzle inputs, with the parameters
+    /// from the config file
+    Bench {
+        /// Days to benchmark [default: all]
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+        days: Vec<u8>,
+
+        /// Untimed runs before sampling
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Timed runs per stage
+        #[arg(long, default_value_t = 10)]
+        samples: usize,
+
+        /// Save the results to this file
+        #[arg(long)]
+        save: Option<PathBuf>,
+
+        /// Compare against results saved with --save
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Flag medians slower than the baseline by more than this many percent
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Time the days on generated inputs of growing sizes and estimate how
+    /// each stage grows
+    Scale {
+        /// Days to profile [default: all]
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+        days: Vec<u8>,
+
+        /// Seed of the inputs
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Smallest input size
+        #[arg(long, default_value_t = 8)]
+        from: usize,
+
+        /// Largest input size
+        #[arg(long, default_value_t = 4096)]
+        to: usize,
+
+        /// Ratio between successive sizes
+        #[arg(long, default_value_t = 2, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(2..))]
+        factor: usize,
+
+        /// Timed runs per stage and size
+        #[arg(long, default_value_t = 3)]
+        samples: usize,
+
+        /// Stop growing a day's input once one run takes this many seconds,
+        /// cancelling runs that take much longer
+        #[arg(long, default_value_t = 1.0)]
+        budget: f64,
+    },
+    /// Save the answers for the puzzle inputs as known answers
+    Record {
+        /// Days to record [default: all]
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+        days: Vec<u8>,
+
+        /// Known answers file
+        #[arg(long, default_value = "answers.txt")]
+        answers: PathBuf,
+    },
+    /// Solve the puzzle inputs of all days in parallel and check them against
+    /// the known answers
+    All {
+        /// Days to run [default: all]
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+        days: Vec<u8>,
+
+        /// Threads to run the days on [default: number of CPUs]
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        jobs: Option<u64>,
+
+        /// Known answers file
+        #[arg(long, default_value = "answers.txt")]
+        answers: PathBuf,
+    },
+    /// Check the answers for the puzzle inputs against the known answers
+    Verify {
+        /// Days to verify [default: all]
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+        days: Vec<u8>,
+
+        /// Known answers file
+        #[arg(long, default_value = "answers.txt")]
+        answers: PathBuf,
+    },
+    /// Compare the real and naive solutions on random small inputs
+    Diff {
+        /// Days to test [default: all]
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+        days: Vec<u8>,
+
+        /// Seed of the first random input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Number of random inputs per day
+        #[arg(long, default_value_t = 100)]
+        cases: usize,
+
+        /// Set a parameter of the days, overriding the config file
+        #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = config::parse_param)]
+        params: Vec<(String, String)>,
+    },
+    /// Print a random puzzle input
+    Gen {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+        day: u8,
+
+        /// Seed of the input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How large the input is, such as its number of lines
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+
+        /// Set a parameter of the day, overriding the config file
+        #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = config::parse_param)]
+        params: Vec<(String, String)>,
+    },
+    /// Check the examples in dayN/examples against their expected answers
+    Examples {
+        /// Days to check [default: all]
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+        days: Vec<u8>,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let mut config = Config::load(cli.config.as_deref()).unwrap_or_else(|err| {
+        eprintln!("fail to read config: {}", err);
+        process::exit(1);
+    });
+    if let Some(timeout) = cli.timeout {
+        config.override_timeout(timeout);
+    }
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            input_dir,
+            format,
+            params,
+            naive,
+        } => {
+            let params = config.params(day, &params);
+            let solve = if naive {
+                DAYS[day as usize - 1].naive
+            } else {
+                DAYS[day as usize - 1].solve
+            };
+            let source = Source::new(day, input, input_dir);
+            let inputs = source.read().unwrap_or_else(|err| {
+                eprintln!("fail to read input: {}", err);
+                process::exit(1);
+            });
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+
+            let timeout = config.timeout(day);
+            if format == Format::Json {
+                run_json(day, solve, &parts, &params, timeout, &inputs);
+            } else if source.is_batch() {
+                run_batch(solve, &parts, &params, timeout, &inputs);
+            } else {
+                let (_, input) = &inputs[0];
+                run(solve, &parts, &params, timeout, input);
+            }
+        }
+        Command::Bench {
+            days,
+            warmup,
+            samples,
+            save,
+            baseline,
+            threshold,
+        } => {
+            let days = or_all_days(days);
+            let baseline = baseline.map(|path| {
+                Results::load(&path).unwrap_or_else(|err| {
+                    eprintln!("fail to read baseline {}: {}", path.display(), err);
+                    process::exit(1);
+                })
+            });
+            let (results, failed) = run_bench(
+                &days,
+                &config,
+                Options { warmup, samples },
+                baseline.as_ref(),
+                threshold,
+            );
+            if let Some(path) = save
+                && let Err(err) = results.save(&path)
+            {
+                eprintln!("fail to save results {}: {}", path.display(), err);
+                process::exit(1);
+            }
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::Scale {
+            days,
+            seed,
+            from,
+            to,
+            factor,
+            samples,
+            budget,
+        } => {
+            let options = scale::Options {
+                seed,
+                from,
+                to,
+                factor,
+                samples,
+                budget: Duration::from_secs_f64(budget),
+            };
+            if !run_scale(&or_all_days(days), &config, options) {
+                process::exit(1);
+            }
+        }
+        Command::Record { days, answers } => {
+            let mut known = load_answers(&answers);
+            record(&or_all_days(days), &config, &mut known);
+            if let Err(err) = known.save(&answers) {
+                eprintln!("fail to save answers {}: {}", answers.display(), err);
+                process::exit(1);
+            }
+        }
+        Command::All {
+            days,
+            jobs,
+            answers,
+        } => {
+            let jobs = jobs.map_or_else(pool::default_jobs, |jobs| jobs as usize);
+            if !run_all(&or_all_days(days), &config, &load_answers(&answers), jobs) {
+                process::exit(1);
+            }
+        }
+        Command::Verify { days, answers } => {
+            if !verify(&or_all_days(days), &config, &load_answers(&answers)) {
+                process::exit(1);
+            }
+        }
+        Command::Diff {
+            days,
+            seed,
+            cases,
+            params,
+        } => {
+            let options = diff::Options { seed, cases };
+            if !run_diff(&or_all_days(days), &config, &params, options) {
+                process::exit(1);
+            }
+        }
+        Command::Gen {
+            day,
+            seed,
+            size,
+            params,
+        } => {
+            let params = config.params(day, &params);
+            match (DAYS[day as usize - 1].generate)(seed, size, &params) {
+                Ok(input) => print!("{}", input),
+                Err(err) => {
+                    eprintln!("{}", err.report(""));
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Examples { days } => {
+            if !run_examples(&or_all_days(days)) {
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn or_all_days(days: Vec<u8>) -> Vec<u8> {
+    if days.is_empty() {
+        (1..=DAYS.len() as u8).collect()
+    } else {
+        days
+    }
+}
+
+fn load_answers(path: &std::path::Path) -> Answers {
+    Answers::load(path).unwrap_or_else(|err| {
+        eprintln!("fail to read answers {}: {}", path.display(), err);
+        process::exit(1);
+    })
+}
+
+/// Reads the default input of `day` and solves both parts, returning the input
+/// hash and the answers or a printable error. Parts are limited to the day's
+/// timeout from `config`, but its parameters are ignored.
+fn solve_default(day: u8, config: &Config) -> Result<(String, Vec<Answer>), String> {
+    let inputs = Source::new(day, None, None)
+        .read()
+        .map_err(|err| err.to_string())?;
+    let (_, input) = &inputs[0];
+    let answers = (DAYS[day as usize - 1].solve)(input, &[1, 2], &[], config.timeout(day))
+        .map_err(|err| err.report(input))?;
+    Ok((input_hash(input), answers))
+}
+
+fn record(days: &[u8], config: &Config, known: &mut Answers) {
+    for &day in days {
+        match solve_default(day, config) {
+            Ok((hash, answers)) => {
+                for answer in answers {
+                    if answer.timed_out {
+                        eprintln!("day {} part{}: timed out", day, answer.part);
+                        process::exit(1);
+                    }
+                    println!("day {} part{}: {}", day, answer.part, answer.value);
+                    known.insert(day, &hash, answer.part, answer.value);
+                }
+            }
+            Err(err) => {
+                eprintln!("day {}: {}", day, err);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Prints a table comparing the answers with the known ones, returning whether
+/// all of them matched.
+fn verify(days: &[u8], config: &Config, known: &Answers) -> bool {
+    let mut table = Table::new(&["day", "part", "expected", "actual", "status"]);
+    let mut ok = true;
+
+    for &day in days {
+        let (hash, answers) = match solve_default(day, config) {
+            Ok(res) => res,
+            Err(err) => {
+                eprintln!("day {}: {}", day, err);
+                ok = false;
+                continue;
+            }
+        };
+        for answer in answers {
+            let (expected, status) = check(known, day, &hash, &answer);
+            ok &= status == "ok";
+            table.push(vec![
+                day.to_string(),
+                answer.part.to_string(),
+                expected.to_owned(),
+                answer.value,
+                status.to_owned(),
+            ]);
+        }
+    }
+
+    print!("{}", table);
+    ok
+}
+
+/// The known answer for `answer` and whether it matches.
+fn check<'a>(known: &'a Answers, day: u8, hash: &str, answer: &Answer) -> (&'a str, &'static str) {
+    match known.get(day, hash, answer.part) {
+        expected if answer.timed_out => (expected.unwrap_or("-"), "timed out"),
+        Some(expected) if expected == answer.value => (expected, "ok"),
+        Some(expected) => (expected, "MISMATCH"),
+        None => ("-", "not recorded"),
+    }
+}
+
+/// Solves the days concurrently on `jobs` threads and prints one table of the
+/// answers checked against the known ones, returning whether all of them
+/// matched. A day that panics is reported without stopping the others.
+fn run_all(days: &[u8], config: &Config, known: &Answers, jobs: usize) -> bool {
+    let start = Instant::now();
+    let results = isolate::quietly(|| {
+        pool::map(days, jobs, |&day| {
+            isolate::catch(|| solve_default(day, config))
+        })
+    });
+    let elapsed = start.elapsed();
+
+    let mut table = Table::new(&["day", "part", "answer", "time", "expected", "status"]);
+    let mut ok = true;
+    for (&day, res) in days.iter().zip(results) {
+        let failure = match res {
+            Ok(Ok((hash, answers))) => {
+                for answer in answers {
+                    let (expected, status) = check(known, day, &hash, &answer);
+                    ok &= status == "ok";
+                    table.push(vec![
+                        day.to_string(),
+                        answer.part.to_string(),
+                        answer.value.clone(),
+                        format!("{:.2?}", answer.elapsed),
+                        expected.to_owned(),
+                        status.to_owned(),
+                    ]);
+                }
+                continue;
+            }
+            Ok(Err(err)) => {
+                eprintln!("day {}: {}", day, err);
+                "error"
+            }
+            Err(message) => {
+                eprintln!("day {}: panicked: {}", day, message);
+                "panicked"
+            }
+        };
+        ok = false;
+        table.push(vec![
+            day.to_string(),
+            "-".to_owned(),
+            "-".to_owned(),
+            "-".to_owned(),
+            "-".to_owned(),
+            failure.to_owned(),
+        ]);
+    }
+
+    print!("{}", table);
+    let threads = jobs.min(days.len());
+    println!(
+        "{} days in {:.2?} on {} thread{}",
+        days.len(),
+        elapsed,
+        threads,
+        if threads == 1 { "" } else { "s" }
+    );
+    ok
+}
+
+fn run(
+    solve: Solver,
+    parts: &[u8],
+    params: &[(String, String)],
+    timeout: Option<Duration>,
+    input: &str,
+) {
+    match solve(input, parts, params, timeout) {
+        Ok(answers) => {
+            for answer in &answers {
+                println!("part{}: {}", answer.part, answer.value);
+            }
+            if answers.iter().any(|answer| answer.timed_out) {
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("{}", err.report(input));
+            process::exit(1);
+        }
+    }
+}
+
+fn run_batch(
+    solve: Solver,
+    parts: &[u8],
+    params: &[(String, String)],
+    timeout: Option<Duration>,
+    inputs: &[(String, String)],
+) {
+    let mut header = vec!["input".to_owned()];
+    header.extend(parts.iter().map(|part| format!("part{}", part)));
+    let mut table = Table::new(&header.iter().map(String::as_str).collect::<Vec<_>>());
+    let mut errors = Vec::new();
+
+    for (label, input) in inputs {
+        let mut row = vec![label.clone()];
+        match solve(input, parts, params, timeout) {
+            Ok(answers) => {
+                for answer in answers {
+                    if answer.timed_out {
+                        errors.push(format!("{}: part{} timed out", label, answer.part));
+                    }
+                    row.push(answer.value);
+                }
+            }
+            Err(err) => {
+                row.extend(parts.iter().map(|_| "error".to_owned()));
+                errors.push(format!("{}: {}", label, err.report(input)));
+            }
+        }
+        table.push(row);
+    }
+
+    print!("{}", table);
+
+    if !errors.is_empty() {
+        for err in errors {
+            eprintln!("\n{}", err);
+        }
+        process::exit(1);
+    }
+}
+
+/// Prints one JSON object per answer, for every input.
+fn run_json(
+    day: u8,
+    solve: Solver,
+    parts: &[u8],
+    params: &[(String, String)],
+    timeout: Option<Duration>,
+    inputs: &[(String, String)],
+) {
+    let mut failed = false;
+
+    for (label, input) in inputs {
+        match solve(input, parts, params, timeout) {
+            Ok(answers) => {
+                let hash = input_hash(input);
+                for answer in answers {
+                    failed |= answer.timed_out;
+                    println!("{}", answer_json(day, label, &hash, &answer));
+                }
+            }
+            Err(err) => {
+                eprintln!("{}: {}", label, err.report(input));
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn answer_json(day: u8, input: &str, hash: &str, answer: &Answer) -> serde_json::Value {
+    serde_json::json!({
+        "day": day,
+        "part": answer.part,
+        "answer": (!answer.timed_out).then_some(&answer.value),
+        "timed_out": answer.timed_out,
+        "input": input,
+        "input_hash": hash,
+        "elapsed_ns": answer.elapsed.as_nanos() as u64,
+    })
+}
+
+/// Benchmarks `days` on their default inputs and prints a table. Also returns
+/// whether any day failed or regressed past `threshold` percent.
+fn run_bench(
+    days: &[u8],
+    config: &Config,
+    options: Options,
+    baseline: Option<&Results>,
+    threshold: f64,
+) -> (Results, bool) {
+    let mut header = vec!["day", "stage", "median", "min", "max"];
+    if baseline.is_some() {
+        header.extend(["baseline", "change"]);
+    }
+    let mut table = Table::new(&header);
+    let mut results = Results::default();
+    let mut failed = false;
+
+    for &day in days {
+        let stats = Source::new(day, None, None)
+            .read()
+            .map_err(|err| err.to_string())
+            .and_then(|inputs| {
+                let (_, input) = &inputs[0];
+                (DAYS[day as usize - 1].bench)(input, &config.params(day, &[]), options)
+                    .map_err(|err| err.report(input))
+            });
+        let stats = match stats {
+            Ok(stats) => stats,
+            Err(err) => {
+                eprintln!("day {}: {}", day, err);
+                failed = true;
+                continue;
+            }
+        };
+
+        for (stage, stats) in bench::STAGES.iter().zip(stats) {
+            let mut row = vec![
+                day.to_string(),
+                stage.to_string(),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.max),
+            ];
+            if let Some(baseline) = baseline {
+                match baseline.0.get(&(day, stage.to_string())) {
+                    Some(base) => {
+                        let change = bench::change(&stats, base);
+                        let flag = if change > threshold {
+                            failed = true;
+                            "  regression"
+                        } else {
+                            ""
+                        };
+                        row.push(format!("{:.2?}", base.median));
+                        row.push(format!("{:+.1}%{}", change, flag));
+                    }
+                    None => row.extend(["-".to_owned(), "-".to_owned()]),
+                }
+            }
+            table.push(row);
+            results.0.insert((day, stage.to_string()), stats);
+        }
+    }
+
+    print!("{}", table);
+    (results, failed)
+}
+
+/// Prints a table of the median time of each stage per input size, with the
+/// growth exponent fitted against the input length, returning whether every day could be profiled.
+fn run_scale(days: &[u8], config: &Config, options: scale::Options) -> bool {
+    let sizes = scale::sizes(options.from, options.to, options.factor);
+    let mut header = vec!["day".to_owned(), "stage".to_owned()];
+    header.extend(sizes.iter().map(|size| format!("n={}", size)));
+    header.push("growth".to_owned());
+    let header: Vec<&str> = header.iter().map(String::as_str).collect();
+    let mut table = Table::new(&header);
+    let mut ok = true;
+
+    for &day in days {
+        let points = match (DAYS[day as usize - 1].scale)(&config.params(day, &[]), options) {
+            Ok(points) => points,
+            Err(err) => {
+                eprintln!("day {}: {}", day, err.report(""));
+                ok = false;
+                continue;
+            }
+        };
+        for (i, stage) in bench::STAGES.iter().enumerate() {
+            let mut row = vec![day.to_string(), stage.to_string()];
+            for j in 0..sizes.len() {
+                row.push(match points.get(j).map(|point| &point.stats) {
+                    Some(Some(stats)) => format!("{:.2?}", stats[i].median),
+                    Some(None) => "timed out".to_owned(),
+                    None => "-".to_owned(),
+                });
+            }
+            row.push(match scale::exponent(&points, i) {
+                Some(exponent) => format!("len^{:.2}", exponent),
+                None => "-".to_owned(),
+            });
+            table.push(row);
+        }
+    }
+
+    print!("{}", table);
+    ok
+}
+
+/// Prints the first disagreement of each day, returning whether there were
+/// none.
+fn run_diff(
+    days: &[u8],
+    config: &Config,
+    params: &[(String, String)],
+    options: diff::Options,
+) -> bool {
+    let mut ok = true;
+    for &day in days {
+        match (DAYS[day as usize - 1].diff)(&config.params(day, params), options) {
+            Ok(None) => println!("day {}: {} random inputs agree", day, options.cases),
+            Ok(Some(disagreement)) => {
+                println!("day {}: {}", day, disagreement);
+                ok = false;
+            }
+            Err(err) => {
+                eprintln!("{}", err.report(""));
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+/// Prints a table of the example results, returning whether all of them
+/// passed.
+fn run_examples(days: &[u8]) -> bool {
+    let mut table = Table::new(&[
+        "day", "example", "part", "params", "expected", "actual", "status",
+    ]);
+    let mut ok = true;
+
+    for &day in days {
+        let dir = PathBuf::from(format!("day{}/examples", day));
+        let examples = match examples::discover(&dir) {
+            Ok(examples) => examples,
+            Err(err) => {
+                eprintln!("day {}: {}: {}", day, dir.display(), err);
+                ok = false;
+                continue;
+            }
+        };
+
+        for outcome in examples::check(DAYS[day as usize - 1].solve, &examples) {
+            let passed = outcome.passed();
+            ok &= passed;
+            let params: Vec<String> = outcome
+                .case
+                .params
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect();
+            let actual = match outcome.actual {
+                Ok(answer) => answer,
+                Err(err) => {
+                    eprintln!("day {} {}: {}", day, outcome.example.name, err);
+                    "error".to_owned()
+                }
+            };
+            table.push(vec![
+                day.to_string(),
+                outcome.example.name.clone(),
+                outcome.case.part.to_string(),
+                params.join(" "),
+                outcome.case.answer.clone(),
+                actual,
+                if passed { "ok" } else { "FAILED" }.to_owned(),
+            ]);
+        }
+    }
+
+    print!("{}", table);
+    ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_inputs() {
+        // Every day must generate inputs that parse and keep its rules, and
+        // its real and naive solutions must agree on them.
+        let options = diff::Options { seed: 0, cases: 20 };
+        for (day, entry) in (1..).zip(&DAYS) {
+            match (entry.diff)(&[], options) {
+                Ok(None) => {}
+                Ok(Some(disagreement)) => panic!("day {}: {}", day, disagreement),
+                Err(err) => panic!("day {}: {}", day, err.report("")),
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        for (day, entry) in (1..).zip(&DAYS) {
+            for size in [1, 10, 100] {
+                let input = (entry.generate)(7, size, &[]).unwrap();
+                assert_eq!(input, (entry.generate)(7, size, &[]).unwrap());
+                if let Err(err) = (entry.solve)(&input, &[], &[], None) {
+                    panic!("day {} size {}: {}", day, size, err.report(&input));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_answer_json() {
+        let answer = Answer {
+            part: 2,
+            value: "25272".to_owned(),
+            elapsed: Duration::from_micros(12),
+            timed_out: false,
+        };
+        assert_eq!(
+            answer_json(8, "day8/input.txt", "00ff", &answer).to_string(),
+            r#"{"answer":"25272","day":8,"elapsed_ns":12000,"input":"day8/input.txt","input_hash":"00ff","part":2,"timed_out":false}"#
+        );
+    }
+}
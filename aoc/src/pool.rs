@@ -0,0 +1,60 @@
+use std::{
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+};
+
+/// Applies `f` to every item on up to `jobs` threads, returning the results
+/// in the order of the items. Threads take the next item as soon as they're
+/// done, so one slow item doesn't hold up the others.
+pub fn map<T: Sync, R: Send>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else {
+                        break;
+                    };
+                    let res = f(item);
+                    results.lock().unwrap()[i] = Some(res);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|res| res.expect("every item is mapped"))
+        .collect()
+}
+
+/// Threads to use when not told otherwise.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let items: Vec<u64> = (0..20).collect();
+        let squares = map(&items, 4, |&n| {
+            thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        });
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        assert!(map(&[] as &[u64], 4, |&n| n).is_empty());
+    }
+}
@@ -0,0 +1,156 @@
+use std::time::Duration;
+
+use common::{Generator, ParseError, Rng};
+
+use crate::{
+    bench::{self, Stats},
+    isolate,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    pub seed: u64,
+    /// Smallest and largest input size.
+    pub from: usize,
+    pub to: usize,
+    /// Ratio between successive sizes.
+    pub factor: usize,
+    pub samples: usize,
+    /// Stop growing the input once one run of all stages takes longer, and
+    /// cancel the runs of a size after this long per sample.
+    pub budget: Duration,
+}
+
+/// Timings of every stage at one input size.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Point {
+    pub size: usize,
+    /// Length of the generated input in bytes.
+    pub len: usize,
+    /// `None` if the runs were cancelled for going over the budget.
+    pub stats: Option<Vec<Stats>>,
+}
+
+/// Sizes from `from` to `to`, each `factor` times the previous one.
+pub fn sizes(from: usize, to: usize, factor: usize) -> Vec<usize> {
+    let mut res = Vec::new();
+    let mut size = from.max(1);
+    while size <= to {
+        res.push(size);
+        size *= factor.max(2);
+    }
+    res
+}
+
+/// Times `S` on generated inputs of growing sizes, until the sizes run out or
+/// the budget is exceeded. Solvers that reach a checkpoint after the budget
+/// are cancelled, ending with a point without stats.
+pub fn profile<S: Generator>(
+    params: &S::Params,
+    options: Options,
+) -> Result<Vec<Point>, ParseError> {
+    let bench_options = bench::Options {
+        warmup: 0,
+        samples: options.samples,
+    };
+    let mut res = Vec::new();
+    for size in sizes(options.from, options.to, options.factor) {
+        let input = S::generate(&mut Rng::new(options.seed), size, params);
+        let timeout = options.budget * options.samples.max(1) as u32;
+        let stats = isolate::with_timeout(Some(timeout), || {
+            bench::bench::<S>(&input, params, bench_options)
+        })
+        .transpose()?;
+        let total: Option<Duration> = stats
+            .as_ref()
+            .map(|stats| stats.iter().map(|stats| stats.min).sum());
+        res.push(Point {
+            size,
+            len: input.len(),
+            stats,
+        });
+        if total.is_none_or(|total| total > options.budget) {
+            break;
+        }
+    }
+    Ok(res)
+}
+
+/// How many of the largest sizes the exponent is fitted over, where fixed
+/// costs matter least.
+const FIT_POINTS: usize = 4;
+
+/// Exponent `k` of the best fit of `time ~ len^k` for one stage, where `len`
+/// is the input length, by least squares on a log-log scale over the largest
+/// sizes. Fitting against the length rather than the size keeps days whose
+/// size is a grid side comparable with the others.
+pub fn exponent(points: &[Point], stage: usize) -> Option<f64> {
+    let xy: Vec<(f64, f64)> = points
+        .iter()
+        .filter_map(|point| {
+            let nanos = point.stats.as_ref()?[stage].median.as_nanos().max(1) as f64;
+            Some(((point.len as f64).ln(), nanos.ln()))
+        })
+        .collect();
+    let xy = &xy[xy.len().saturating_sub(FIT_POINTS)..];
+    if xy.len() < 2 {
+        return None;
+    }
+
+    let n = xy.len() as f64;
+    let mean_x = xy.iter().map(|&(x, _)| x).sum::<f64>() / n;
+    let mean_y = xy.iter().map(|&(_, y)| y).sum::<f64>() / n;
+    let cov: f64 = xy.iter().map(|&(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let var: f64 = xy.iter().map(|&(x, _)| (x - mean_x).powi(2)).sum();
+    Some(cov / var)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(len: usize, nanos: u64) -> Point {
+        let time = Duration::from_nanos(nanos);
+        let stats = Stats {
+            median: time,
+            min: time,
+            max: time,
+        };
+        Point {
+            size: len,
+            len,
+            stats: Some(vec![stats]),
+        }
+    }
+
+    #[test]
+    fn test_sizes() {
+        assert_eq!(sizes(8, 100, 2), vec![8, 16, 32, 64]);
+        assert_eq!(sizes(1, 1000, 10), vec![1, 10, 100, 1000]);
+        assert!(sizes(10, 1, 2).is_empty());
+    }
+
+    #[test]
+    fn test_exponent() {
+        // A constant cost at the smallest size is left out of the fit.
+        let quadratic: Vec<Point> = [(1, 5000), (10, 100), (20, 400), (40, 1600), (80, 6400)]
+            .iter()
+            .map(|&(len, nanos)| point(len, nanos))
+            .collect();
+        assert_eq!((exponent(&quadratic, 0).unwrap() * 100.0).round(), 200.0);
+
+        let linear = [point(10, 30), point(100, 300)];
+        assert_eq!((exponent(&linear, 0).unwrap() * 100.0).round(), 100.0);
+
+        assert_eq!(exponent(&[point(10, 30)], 0), None);
+
+        // Cancelled sizes are left out too.
+        let cancelled = Point {
+            size: 1000,
+            len: 1000,
+            stats: None,
+        };
+        let linear = [point(10, 30), point(100, 300), cancelled];
+        assert_eq!((exponent(&linear, 0).unwrap() * 100.0).round(), 100.0);
+    }
+}
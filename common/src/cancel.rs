@@ -0,0 +1,87 @@
+use std::{
+    cell::Cell,
+    panic,
+    time::{Duration, Instant},
+};
+
+/// Payload of the panic raised by [`checkpoint`] once the deadline has
+/// passed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+thread_local! {
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Runs `f` with a deadline `timeout` from now on the current thread, or
+/// without one for `None`. Deadlines nest, restoring the outer one after.
+pub fn with_timeout<T>(timeout: Option<Duration>, f: impl FnOnce() -> T) -> T {
+    let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+    let outer = DEADLINE.replace(deadline);
+    struct Restore(Option<Instant>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            DEADLINE.set(self.0);
+        }
+    }
+    let _restore = Restore(outer);
+    f()
+}
+
+/// Unwinds with [`Cancelled`] if the deadline of the current thread has
+/// passed. Long running solvers call it in their loops, so they stop soon
+/// after their time is up instead of running on. It reads the clock, so call
+/// it once per unit of real work rather than in the tightest loop.
+pub fn checkpoint() {
+    if let Some(deadline) = DEADLINE.get()
+        && Instant::now() >= deadline
+    {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+/// Time left until the deadline of the current thread, zero once it has
+/// passed, or `None` without one. For work that can't call [`checkpoint`],
+/// such as an external solver with a time limit of its own.
+pub fn remaining() -> Option<Duration> {
+    DEADLINE
+        .get()
+        .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spin() -> u64 {
+        let mut n = 0_u64;
+        loop {
+            checkpoint();
+            n = n.wrapping_add(1);
+        }
+    }
+
+    #[test]
+    fn test_timeout() {
+        let res = panic::catch_unwind(|| with_timeout(Some(Duration::from_millis(10)), spin));
+        assert!(res.unwrap_err().is::<Cancelled>());
+        assert_eq!(DEADLINE.get(), None);
+        assert_eq!(remaining(), None);
+        with_timeout(Some(Duration::from_secs(60)), || {
+            assert!(remaining().is_some_and(|left| left > Duration::from_secs(59)));
+        });
+    }
+
+    #[test]
+    fn test_no_timeout() {
+        assert_eq!(
+            with_timeout(None, || {
+                for _ in 0..10_000 {
+                    checkpoint();
+                }
+                1
+            }),
+            1
+        );
+    }
+}
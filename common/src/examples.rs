@@ -0,0 +1,103 @@
+use std::fmt::Write;
+
+use crate::{Params, Solution};
+
+/// One expected answer of an example, under a set of parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Case {
+    pub part: u8,
+    pub params: Vec<(String, String)>,
+    pub answer: String,
+}
+
+/// Parses expected answers, one `partN [name=value]...: answer` per line.
+/// Blank lines and lines starting with `#` are skipped.
+pub fn parse_expected(text: &str) -> Result<Vec<Case>, String> {
+    let mut res = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = |message: &str| format!("line {}: {} in {:?}", i + 1, message, line);
+
+        let (head, answer) = line
+            .split_once(':')
+            .ok_or_else(|| invalid("expected \"partN: answer\""))?;
+        let mut words = head.split_whitespace();
+        let part = match words.next() {
+            Some("part1") => 1,
+            Some("part2") => 2,
+            _ => return Err(invalid("expected part1 or part2")),
+        };
+        let params = words
+            .map(|word| {
+                word.split_once('=')
+                    .map(|(name, value)| (name.to_owned(), value.to_owned()))
+                    .ok_or_else(|| invalid("expected name=value"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        res.push(Case {
+            part,
+            params,
+            answer: answer.trim().to_owned(),
+        });
+    }
+    Ok(res)
+}
+
+/// Solves every case of an example with `S`, panicking with the ones that
+/// fail. Days test their examples with it, reading the fixture files from
+/// their `examples` directory with `include_str!`.
+pub fn assert_example<S: Solution>(input: &str, expected: &str) {
+    let cases = parse_expected(expected).unwrap();
+    assert!(!cases.is_empty(), "no expected answers");
+    let parsed = S::parse(input).unwrap_or_else(|err| panic!("{}", err.report(input)));
+
+    let mut failures = String::new();
+    for case in &cases {
+        let mut params = S::Params::default();
+        for (name, value) in &case.params {
+            params.set(name, value).unwrap();
+        }
+        let actual = S::check(&parsed, &params, case.part).map(|()| match case.part {
+            1 => S::part1(&parsed, &params).to_string(),
+            _ => S::part2(&parsed, &params).to_string(),
+        });
+        if actual.as_ref() != Ok(&case.answer) {
+            writeln!(failures, "{:?}: got {:?}", case, actual).unwrap();
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expected() {
+        assert_eq!(
+            parse_expected("# example\npart1 connections=10 count=3: 40\n\npart2: 25272\n"),
+            Ok(vec![
+                Case {
+                    part: 1,
+                    params: vec![
+                        ("connections".to_owned(), "10".to_owned()),
+                        ("count".to_owned(), "3".to_owned())
+                    ],
+                    answer: "40".to_owned(),
+                },
+                Case {
+                    part: 2,
+                    params: vec![],
+                    answer: "25272".to_owned(),
+                },
+            ])
+        );
+        assert!(parse_expected("part3: 1").is_err());
+        assert!(parse_expected("part1 10: 1").is_err());
+        assert!(parse_expected("part1 1").is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use std::{fmt::Display, fs, path::Path};
+
+pub mod cancel;
+pub mod examples;
+pub mod params;
+mod parse;
+mod rng;
+mod wide;
+
+pub use params::Params;
+pub use parse::{Line, ParseError, lines};
+pub use rng::Rng;
+pub use wide::Wide;
+
+/// A day's puzzle split into parsing and the two parts, so every day can be
+/// driven the same way.
+pub trait Solution {
+    const DAY: u8;
+
+    /// Parsed puzzle input shared by both parts.
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+    /// Tunable constants, `()` for days without any.
+    type Params: Params;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> Self::Answer1;
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Self::Answer2;
+
+    /// Checks that `params` suit the parsed input for `part`, such as naming
+    /// nodes that are in it, before the part runs.
+    fn check(_input: &Self::Input<'_>, _params: &Self::Params, _part: u8) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Random puzzle inputs of a day, for testing and profiling the solutions.
+pub trait Generator: Solution {
+    /// A random input in the exact format `parse` accepts, growing with
+    /// `size`. The meaning of the size is up to the day, such as the number
+    /// of lines or the side of a grid, and the same seed and size always give
+    /// the same input.
+    fn generate(rng: &mut Rng, size: usize, params: &Self::Params) -> String;
+}
+
+/// Slow but obviously correct solutions of a day, to check the real ones
+/// against on random inputs.
+pub trait Reference: Generator {
+    /// Solves part 1 from the raw input, which is known to parse.
+    fn naive1(input: &str, params: &Self::Params) -> Self::Answer1;
+    fn naive2(input: &str, params: &Self::Params) -> Self::Answer2;
+
+    /// Whether an input that parses also keeps the puzzle's guarantees, such
+    /// as reachable nodes, so shrinking a failing input doesn't leave them.
+    fn is_valid(_input: &str, _params: &Self::Params) -> bool {
+        true
+    }
+}
+
+/// Solves both parts of the input file at `path` with the default parameters,
+/// as a day's own binary does, returning the answers or a printable error.
+pub fn solve_file<S: Solution>(path: &Path) -> Result<[String; 2], String> {
+    let input = fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+    let parsed = S::parse(&input).map_err(|err| err.report(&input))?;
+    let params = S::Params::default();
+    for part in [1, 2] {
+        S::check(&parsed, &params, part)?;
+    }
+    Ok([
+        S::part1(&parsed, &params).to_string(),
+        S::part2(&parsed, &params).to_string(),
+    ])
+}
@@ -0,0 +1,44 @@
+use std::str::FromStr;
+
+/// Tunable constants of a day, set by name from text such as command line
+/// arguments or example fixtures.
+pub trait Params: Default {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+}
+
+/// Days without parameters.
+impl Params for () {
+    fn set(&mut self, name: &str, _: &str) -> Result<(), String> {
+        Err(unknown(name))
+    }
+}
+
+/// Error for a parameter name the day doesn't have.
+pub fn unknown(name: &str) -> String {
+    format!("unknown parameter {:?}", name)
+}
+
+/// Parses the `value` of parameter `name`.
+pub fn value<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value {:?} for parameter {}", value, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value() {
+        assert_eq!(value::<usize>("count", "3"), Ok(3));
+        assert_eq!(
+            value::<usize>("count", "x"),
+            Err(r#"invalid value "x" for parameter count"#.to_owned())
+        );
+        assert_eq!(
+            ().set("count", "3"),
+            Err(r#"unknown parameter "count""#.to_owned())
+        );
+    }
+}
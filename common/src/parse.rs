@@ -0,0 +1,144 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Malformed puzzle input, pointing at the offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        day: u8,
+        line: usize,
+        column: usize,
+        token: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            token: token.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Renders the error followed by the offending input line with the token
+    /// underlined.
+    pub fn report(&self, input: &str) -> String {
+        let mut res = self.to_string();
+        if let Some(text) = input.lines().nth(self.line.saturating_sub(1)) {
+            let gutter = self.line.to_string().len();
+            let marks = self.token.chars().count().max(1);
+            res.push_str(&format!(
+                "\n{} | {}\n{} | {}{}",
+                self.line,
+                text,
+                " ".repeat(gutter),
+                " ".repeat(self.column.saturating_sub(1)),
+                "^".repeat(marks),
+            ));
+        }
+        res
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        if !self.token.is_empty() {
+            write!(f, " {:?}", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// One line of puzzle input, used to build errors that point into it.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Builds an error for `token`, which should be a subslice of this line so
+    /// its column can be worked out.
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.day, self.number, self.column(token), token, message)
+    }
+
+    /// Parses `token`, naming it `what` in the error if it's not valid.
+    pub fn parse<T: FromStr>(&self, token: &str, what: &str) -> Result<T, ParseError> {
+        token
+            .parse()
+            .map_err(|_| self.error(token, format!("invalid {}", what)))
+    }
+
+    fn column(&self, token: &str) -> usize {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|&offset| offset <= self.text.len())
+            .unwrap_or(0);
+        self.text[..offset].chars().count() + 1
+    }
+}
+
+/// Iterates over the lines of `input` along with their line numbers.
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(i, text)| Line {
+        day,
+        number: i + 1,
+        text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_error() {
+        let input = "1,2\n3,x4";
+        let line = lines(8, input).nth(1).unwrap();
+        let (_, token) = line.text.split_once(',').unwrap();
+
+        assert_eq!(
+            line.parse::<u64>(token, "number"),
+            Err(ParseError::new(8, 2, 3, "x4", "invalid number"))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ParseError::new(5, 3, 4, "1x", "invalid number").to_string(),
+            r#"day 5, line 3, column 4: invalid number "1x""#
+        );
+        assert_eq!(
+            ParseError::new(5, 3, 1, "", "expected range").to_string(),
+            "day 5, line 3, column 1: expected range"
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        assert_eq!(
+            ParseError::new(5, 2, 4, "1x", "invalid number").report("3-5\n10-1x"),
+            "day 5, line 2, column 4: invalid number \"1x\"\n2 | 10-1x\n  |    ^^"
+        );
+    }
+}
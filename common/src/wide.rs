@@ -0,0 +1,269 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign},
+};
+
+/// A non-negative integer of any size for answers that can overflow. It is a
+/// `u128` while the value fits, checking every operation and moving to
+/// arbitrary precision when it doesn't.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Wide(Repr);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Repr {
+    Small(u128),
+    /// Little endian limbs of a value above `u128::MAX`, without leading
+    /// zeros, so every value has one representation.
+    Big(Vec<u64>),
+}
+
+impl Wide {
+    pub const ZERO: Self = Self(Repr::Small(0));
+    pub const ONE: Self = Self(Repr::Small(1));
+
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut base = self.clone();
+        let mut res = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res *= base.clone();
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base *= base.clone();
+            }
+        }
+        res
+    }
+
+    fn limbs(&self) -> Vec<u64> {
+        match &self.0 {
+            Repr::Small(n) => vec![*n as u64, (n >> 64) as u64],
+            Repr::Big(limbs) => limbs.clone(),
+        }
+    }
+
+    fn from_limbs(mut limbs: Vec<u64>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        match limbs[..] {
+            [] => Self::ZERO,
+            [lo] => Self(Repr::Small(lo as u128)),
+            [lo, hi] => Self(Repr::Small((hi as u128) << 64 | lo as u128)),
+            _ => Self(Repr::Big(limbs)),
+        }
+    }
+}
+
+impl Default for Wide {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Wide {
+            fn from(n: $t) -> Self {
+                Self(Repr::Small(n as u128))
+            }
+        }
+    )*};
+}
+
+from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl TryFrom<Wide> for u64 {
+    type Error = Wide;
+
+    /// Fails with the value itself if it doesn't fit.
+    fn try_from(n: Wide) -> Result<u64, Wide> {
+        match n.0 {
+            Repr::Small(small) => small.try_into().map_err(|_| n),
+            Repr::Big(_) => Err(n),
+        }
+    }
+}
+
+impl PartialEq<u64> for Wide {
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == Repr::Small(*other as u128)
+    }
+}
+
+impl Ord for Wide {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (Repr::Small(a), Repr::Small(b)) => a.cmp(b),
+            (Repr::Small(_), Repr::Big(_)) => Ordering::Less,
+            (Repr::Big(_), Repr::Small(_)) => Ordering::Greater,
+            (Repr::Big(a), Repr::Big(b)) => a
+                .len()
+                .cmp(&b.len())
+                .then_with(|| a.iter().rev().cmp(b.iter().rev())),
+        }
+    }
+}
+
+impl PartialOrd for Wide {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Wide {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if let (Repr::Small(a), Repr::Small(b)) = (&self.0, &other.0)
+            && let Some(sum) = a.checked_add(*b)
+        {
+            return Self(Repr::Small(sum));
+        }
+
+        let (a, b) = (self.limbs(), other.limbs());
+        let mut res = Vec::with_capacity(a.len().max(b.len()) + 1);
+        let mut carry = 0;
+        for i in 0..a.len().max(b.len()) {
+            let sum = *a.get(i).unwrap_or(&0) as u128 + *b.get(i).unwrap_or(&0) as u128 + carry;
+            res.push(sum as u64);
+            carry = sum >> 64;
+        }
+        res.push(carry as u64);
+        Self::from_limbs(res)
+    }
+}
+
+impl Mul for Wide {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        if let (Repr::Small(a), Repr::Small(b)) = (&self.0, &other.0)
+            && let Some(product) = a.checked_mul(*b)
+        {
+            return Self(Repr::Small(product));
+        }
+
+        // Schoolbook multiplication, one limb of `a` at a time.
+        let (a, b) = (self.limbs(), other.limbs());
+        let mut res = vec![0_u64; a.len() + b.len()];
+        for (i, &x) in a.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &y) in b.iter().enumerate() {
+                let cur = x as u128 * y as u128 + res[i + j] as u128 + carry;
+                res[i + j] = cur as u64;
+                carry = cur >> 64;
+            }
+            res[i + b.len()] = carry as u64;
+        }
+        Self::from_limbs(res)
+    }
+}
+
+impl AddAssign for Wide {
+    fn add_assign(&mut self, other: Self) {
+        *self = std::mem::take(self) + other;
+    }
+}
+
+impl MulAssign for Wide {
+    fn mul_assign(&mut self, other: Self) {
+        *self = std::mem::take(self) * other;
+    }
+}
+
+impl<T: Into<Wide>> Sum<T> for Wide {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |sum, n| sum + n.into())
+    }
+}
+
+impl<T: Into<Wide>> Product<T> for Wide {
+    fn product<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |product, n| product * n.into())
+    }
+}
+
+impl fmt::Display for Wide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut limbs = match &self.0 {
+            Repr::Small(n) => return write!(f, "{}", n),
+            Repr::Big(limbs) => limbs.clone(),
+        };
+
+        // Split off 19 decimal digits at a time, least significant first.
+        const CHUNK: u64 = 10_u64.pow(19);
+        let mut chunks = Vec::new();
+        while !limbs.is_empty() {
+            let mut rem = 0_u128;
+            for limb in limbs.iter_mut().rev() {
+                let cur = rem << 64 | *limb as u128;
+                *limb = (cur / CHUNK as u128) as u64;
+                rem = cur % CHUNK as u128;
+            }
+            chunks.push(rem as u64);
+            while limbs.last() == Some(&0) {
+                limbs.pop();
+            }
+        }
+
+        let (first, rest) = chunks.split_last().expect("a big value has digits");
+        write!(f, "{}", first)?;
+        for chunk in rest.iter().rev() {
+            write!(f, "{:019}", chunk)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small() {
+        let n = Wide::from(6_u64) * Wide::from(7_u64) + Wide::ONE;
+        assert_eq!(n, 43);
+        assert_eq!(n.to_string(), "43");
+        assert_eq!(u64::try_from(n), Ok(43));
+        assert_eq!([1_u64, 2, 3].into_iter().sum::<Wide>(), 6);
+        assert_eq!([2_u64, 3, 4].into_iter().product::<Wide>(), 24);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = Wide::from(u128::MAX);
+        assert_eq!(
+            (max.clone() + Wide::ONE).to_string(),
+            "340282366920938463463374607431768211456"
+        );
+        assert_eq!(
+            (max.clone() * max.clone()).to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+        assert_eq!(
+            Wide::from(10_u64).pow(40).to_string(),
+            format!("1{}", "0".repeat(40))
+        );
+        assert_eq!(
+            [u64::MAX; 3].into_iter().sum::<Wide>().to_string(),
+            "55340232221128654845"
+        );
+        assert!(u64::try_from(Wide::from(u64::MAX) + Wide::ONE).is_err());
+    }
+
+    #[test]
+    fn test_ord() {
+        let big = Wide::from(10_u64).pow(40);
+        let bigger = big.clone() + Wide::ONE;
+        let small = Wide::from(u128::MAX);
+        assert!(small < big);
+        assert!(big < bigger);
+        assert_eq!(big.clone().max(bigger.clone()), bigger);
+        // Values back below `u128::MAX` compare equal to small ones again.
+        assert_eq!(Wide::from_limbs(vec![5, 0, 0]), Wide::from(5_u64));
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt;
+
+/// A dial with positions `0..modulus`, turning right towards higher numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    modulus: i64,
+    position: i64,
+}
+
+impl Dial {
+    /// A dial pointing at `start`, taken modulo `modulus`, which must be
+    /// positive.
+    pub fn new(modulus: i64, start: i64) -> Self {
+        assert!(modulus > 0, "dial needs positions, got modulus {}", modulus);
+        Self {
+            modulus,
+            position: start.rem_euclid(modulus),
+        }
+    }
+
+    pub fn modulus(&self) -> i64 {
+        self.modulus
+    }
+
+    pub fn position(&self) -> i64 {
+        self.position
+    }
+
+    /// Turns by `amount`, negative to the left.
+    pub fn rotate(&mut self, amount: i128) -> Event {
+        let (m, start) = (self.modulus as u128, self.position as u128);
+        let (steps, rest) = (amount.unsigned_abs() / m, amount.unsigned_abs() % m);
+        // How far the first 0 is in the direction of the turn, a whole turn
+        // away when starting there. Splitting off the whole turns first keeps
+        // the sums below `2 * m`, whatever the amount.
+        let (ahead, end) = if amount >= 0 {
+            ((m - start) % m, (start + rest) % m)
+        } else {
+            (start, (start + m - rest) % m)
+        };
+        let ahead = if ahead == 0 { m } else { ahead };
+        let zeros = steps + u128::from(rest >= ahead);
+
+        self.position = end as i64;
+        let landed = end == 0;
+        Event {
+            start: start as i64,
+            end: self.position,
+            amount,
+            passes: zeros - u128::from(landed && amount != 0),
+            landed,
+        }
+    }
+
+    /// The event of each of `turns` in order, starting from this dial.
+    pub fn events(mut self, turns: impl IntoIterator<Item = i128>) -> impl Iterator<Item = Event> {
+        turns.into_iter().map(move |amount| self.rotate(amount))
+    }
+}
+
+/// What one rotation of a dial did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Event {
+    pub start: i64,
+    pub end: i64,
+    /// Negative to the left.
+    pub amount: i128,
+    /// Times the dial pointed at 0 before the end of the rotation.
+    pub passes: u128,
+    /// Whether the dial is left at 0, even by a rotation of 0.
+    pub landed: bool,
+}
+
+impl Event {
+    /// Times the dial moved onto 0, the end of the rotation included.
+    pub fn zeros(&self) -> u128 {
+        self.passes + u128::from(self.landed && self.amount != 0)
+    }
+}
+
+/// One line of a timeline, such as `50 L68 -> 82, passed 0 once`.
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = if self.amount < 0 { 'L' } else { 'R' };
+        write!(
+            f,
+            "{} {}{} -> {}",
+            self.start,
+            direction,
+            self.amount.unsigned_abs(),
+            self.end
+        )?;
+        match self.passes {
+            0 => {}
+            1 => write!(f, ", passed 0 once")?,
+            n => write!(f, ", passed 0 {} times", n)?,
+        }
+        if self.landed {
+            write!(f, ", landed on 0")?;
+        }
+        Ok(())
+    }
+}
+
+/// The event of every rotation of the dial.
+pub fn events(dial: Dial, turns: &[i32]) -> impl Iterator<Item = Event> {
+    dial.events(turns.iter().map(|&n| n.into()))
+}
+
+/// Number of rotations that leave the dial at 0.
+pub fn zero_hits(dial: Dial, turns: &[i32]) -> u64 {
+    events(dial, turns).filter(|event| event.landed).count() as u64
+}
+
+/// Number of times the dial points at 0 during or after a rotation.
+pub fn zero_passes(dial: Dial, turns: &[i32]) -> u128 {
+    events(dial, turns).map(|event| event.zeros()).sum()
+}
+
+/// A rotation of one dial of a [`Safe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    pub dial: usize,
+    pub amount: i32,
+}
+
+impl Turn {
+    pub fn new(dial: usize, amount: i32) -> Self {
+        Self { dial, amount }
+    }
+}
+
+/// Several dials, each turned on its own and with its own size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Safe {
+    dials: Vec<Dial>,
+}
+
+impl Safe {
+    pub fn new(dials: Vec<Dial>) -> Self {
+        Self { dials }
+    }
+
+    pub fn dials(&self) -> &[Dial] {
+        &self.dials
+    }
+
+    /// Rotates the dial `turn` is for.
+    pub fn rotate(&mut self, turn: Turn) -> Event {
+        self.dials[turn.dial].rotate(turn.amount.into())
+    }
+
+    /// Number of rotations that leave their dial at 0.
+    pub fn zero_hits(mut self, turns: &[Turn]) -> u64 {
+        turns
+            .iter()
+            .filter(|&&turn| self.rotate(turn).landed)
+            .count() as u64
+    }
+
+    /// Number of times any dial points at 0 during or after a rotation.
+    pub fn zero_passes(mut self, turns: &[Turn]) -> u128 {
+        turns.iter().map(|&turn| self.rotate(turn).zeros()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate() {
+        let mut dial = Dial::new(10, 3);
+        assert_eq!(dial.rotate(6).zeros(), 0);
+        assert_eq!(dial.rotate(1).zeros(), 1);
+        assert_eq!(dial.position(), 0);
+        assert_eq!(dial.rotate(-1).zeros(), 0);
+        assert_eq!(dial.rotate(-9).zeros(), 1);
+        assert_eq!(dial.rotate(25).zeros(), 2);
+        assert_eq!(dial.position(), 5);
+        assert_eq!(dial.rotate(-25).zeros(), 3);
+        assert_eq!(dial.rotate(0).zeros(), 0);
+        assert_eq!(Dial::new(7, -1).position(), 6);
+
+        let mut dial = Dial::new(100, 50);
+        let event = dial.rotate(i128::MAX);
+        assert_eq!(event.zeros(), i128::MAX as u128 / 100);
+        assert_eq!(event.end, 77);
+        assert_eq!(dial.rotate(i128::MIN).end, 49);
+    }
+
+    #[test]
+    fn test_events() {
+        let event = |start, end, amount, passes, landed| Event {
+            start,
+            end,
+            amount,
+            passes,
+            landed,
+        };
+        let mut dial = Dial::new(100, 0);
+        assert_eq!(dial.rotate(-5), event(0, 95, -5, 0, false));
+        assert_eq!(dial.rotate(5), event(95, 0, 5, 0, true));
+        assert_eq!(dial.rotate(-100), event(0, 0, -100, 0, true));
+        assert_eq!(dial.rotate(250), event(0, 50, 250, 2, false));
+        assert_eq!(dial.rotate(-250), event(50, 0, -250, 2, true));
+
+        // Staying at 0 lands there without moving onto it.
+        let still = dial.rotate(0);
+        assert!(still.landed);
+        assert_eq!(still.zeros(), 0);
+    }
+
+    #[test]
+    fn test_safe() {
+        let safe = Safe::new(vec![Dial::new(10, 5), Dial::new(4, 0)]);
+        let turns = [
+            Turn::new(0, 5),
+            Turn::new(1, -4),
+            Turn::new(1, 6),
+            Turn::new(0, -12),
+        ];
+        assert_eq!(safe.clone().zero_hits(&turns), 2);
+        assert_eq!(safe.zero_passes(&turns), 4);
+    }
+}
@@ -0,0 +1,20 @@
+use common::{Generator, Rng};
+
+use crate::{Day1, Params};
+
+/// `size` rotations, a few of them whole turns of the dial.
+impl Generator for Day1 {
+    fn generate(rng: &mut Rng, size: usize, _: &Params) -> String {
+        let mut res = String::new();
+        for _ in 0..size {
+            let direction = rng.pick(&['L', 'R']);
+            let amount = match rng.range(0..=3) {
+                0 => rng.range(1..=3) * 100,
+                1 => rng.range(1..=350),
+                _ => rng.range(1..=99),
+            };
+            res.push_str(&format!("{}{}\n", direction, amount));
+        }
+        res
+    }
+}
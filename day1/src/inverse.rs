@@ -0,0 +1,149 @@
+use std::collections::BTreeMap;
+
+/// The answers for every starting position of a dial, indexed by the start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Starts {
+    /// Rotations that leave the dial at 0, part 1's count.
+    pub hits: Vec<u64>,
+    /// Times the dial points at 0 during or after a rotation, part 2's count.
+    pub zeros: Vec<u128>,
+}
+
+/// Counts `turns` from every start of a dial with `modulus` positions at once,
+/// in time linear in the turns plus the modulus rather than their product.
+pub fn by_start(modulus: i64, turns: &[i32]) -> Starts {
+    assert!(modulus > 0, "dial needs positions, got modulus {}", modulus);
+    let m = modulus as usize;
+    let mut hits = vec![0; m];
+    // Starts gaining one extra pass of 0, as a difference array.
+    let mut extra = vec![0_i64; m + 1];
+    let mut cover = |from: usize, to: usize| {
+        extra[from] += 1;
+        extra[to + 1] -= 1;
+    };
+    let mut steps = 0_u128;
+    // Where the dial is relative to its start, so it's at `start + offset`.
+    let mut offset = 0_i64;
+
+    for &n in turns {
+        let n = i64::from(n);
+        steps += (n.unsigned_abs() / modulus as u64) as u128;
+        let rest = n.unsigned_abs() as i64 % modulus;
+        // A start `s` is at `p = (s + offset) % m`, and passes 0 once more
+        // than the whole turns when `p` is within `rest` of 0 ahead of it.
+        if rest > 0 {
+            let (lo, hi) = if n > 0 {
+                (modulus - rest, modulus - 1)
+            } else {
+                (1, rest)
+            };
+            let from = (lo - offset).rem_euclid(modulus) as usize;
+            let to = (hi - offset).rem_euclid(modulus) as usize;
+            if from <= to {
+                cover(from, to);
+            } else {
+                cover(from, m - 1);
+                cover(0, to);
+            }
+        }
+
+        offset = (offset + n).rem_euclid(modulus);
+        // The start this rotation leaves at 0.
+        hits[(-offset).rem_euclid(modulus) as usize] += 1;
+    }
+
+    let mut running = 0;
+    let zeros = extra[..m]
+        .iter()
+        .map(|&delta| {
+            running += delta;
+            steps + running as u128
+        })
+        .collect();
+    Starts { hits, zeros }
+}
+
+/// The start with the largest result, the smallest such start on ties.
+pub fn best<T: Ord + Copy>(results: &[T]) -> Option<(i64, T)> {
+    let (start, &res) = results
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|&(_, res)| res)?;
+    Some((start as i64, res))
+}
+
+/// The start with the smallest result, the smallest such start on ties.
+pub fn worst<T: Ord + Copy>(results: &[T]) -> Option<(i64, T)> {
+    let (start, &res) = results.iter().enumerate().min_by_key(|&(_, res)| res)?;
+    Some((start as i64, res))
+}
+
+/// How many starts give each result.
+pub fn histogram<T: Ord + Copy>(results: &[T]) -> BTreeMap<T, usize> {
+    let mut res = BTreeMap::new();
+    for &result in results {
+        *res.entry(result).or_insert(0) += 1;
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dial, zero_hits, zero_passes};
+
+    /// Checks against a separate run from every start.
+    fn check(modulus: i64, turns: &[i32]) {
+        let starts = by_start(modulus, turns);
+        for start in 0..modulus {
+            let dial = Dial::new(modulus, start);
+            let s = start as usize;
+            assert_eq!(
+                starts.hits[s],
+                zero_hits(dial, turns),
+                "hits from {}",
+                start
+            );
+            assert_eq!(
+                starts.zeros[s],
+                zero_passes(dial, turns),
+                "zeros from {}",
+                start
+            );
+        }
+    }
+
+    #[test]
+    fn test_by_start() {
+        let example = [-68, -30, 48, -5, 60, -55, -1, -99, 14, -82];
+        check(100, &example);
+        check(10, &example);
+        check(1, &example);
+        check(7, &[0, 7, -7, 3, -300, 250, -1, 1, 0]);
+        check(5, &[]);
+    }
+
+    #[test]
+    fn test_extremes() {
+        let starts = by_start(100, &[-68, -30, 48, -5, 60, -55, -1, -99, 14, -82]);
+        assert_eq!(starts.hits[50], 3);
+        assert_eq!(starts.zeros[50], 6);
+        assert_eq!(worst(&starts.hits), Some((0, 0)));
+        let (start, most) = best(&starts.zeros).unwrap();
+        assert!(starts.zeros.iter().all(|&zeros| zeros <= most));
+        assert!(
+            starts.zeros[..start as usize]
+                .iter()
+                .all(|&zeros| zeros < most)
+        );
+        assert_eq!(best(&[1, 3, 2, 3]), Some((1, 3)));
+        assert_eq!(worst(&[2, 1, 1]), Some((1, 1)));
+        assert_eq!(best::<u64>(&[]), None);
+        assert_eq!(
+            histogram(&[1, 3, 2, 3]),
+            BTreeMap::from([(1, 1), (2, 1), (3, 2)])
+        );
+        assert_eq!(histogram(&starts.hits).values().sum::<usize>(), 100);
+    }
+}
@@ -0,0 +1,297 @@
+use std::str::FromStr;
+
+use common::{Line, ParseError, Solution, params};
+
+mod dial;
+mod generate;
+mod inverse;
+mod naive;
+mod script;
+mod stream;
+
+pub use dial::{Dial, Event, Safe, Turn, events, zero_hits, zero_passes};
+pub use inverse::{Starts, best, by_start, histogram, worst};
+pub use script::MAX_ROTATIONS;
+pub use stream::{Counts, StreamError, count_stream};
+
+pub struct Day1;
+
+/// Where the dial points before the first rotation, and its number of
+/// positions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Params {
+    pub initial: i64,
+    pub modulus: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            initial: 50,
+            modulus: SIZE,
+        }
+    }
+}
+
+impl Params {
+    pub fn dial(&self) -> Dial {
+        Dial::new(self.modulus, self.initial)
+    }
+}
+
+impl common::Params for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "initial" => self.initial = params::value(name, value)?,
+            "modulus" => {
+                let modulus = params::value(name, value)?;
+                if modulus <= 0 {
+                    return Err(format!("{} must be positive", name));
+                }
+                self.modulus = modulus;
+            }
+            _ => return Err(params::unknown(name)),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<i32>;
+    type Answer1 = u64;
+    type Answer2 = u128;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(turns: &Vec<i32>, params: &Params) -> u64 {
+        zero_hits(params.dial(), turns)
+    }
+
+    fn part2(turns: &Vec<i32>, params: &Params) -> u128 {
+        zero_passes(params.dial(), turns)
+    }
+}
+
+/// Number of positions of the puzzle's dial.
+pub const SIZE: i64 = 100;
+
+/// Number of rotations that leave the puzzle's dial at 0, starting from
+/// `initial`.
+pub fn part1(turns: &[i32], initial: i64) -> u64 {
+    zero_hits(Dial::new(SIZE, initial), turns)
+}
+
+/// Number of times the puzzle's dial points at 0 during or after a rotation.
+pub fn part2(turns: &[i32], initial: i64) -> u128 {
+    zero_passes(Dial::new(SIZE, initial), turns)
+}
+
+/// Rotations as signed amounts, negative to the left. Lines hold any number
+/// of rotations separated by commas or spaces, `3x(R10 L5)` repeats a block
+/// and `#` starts a comment. There can be at most [`MAX_ROTATIONS`] in all.
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    let mut res = Vec::new();
+    let mut left = MAX_ROTATIONS;
+    for line in common::lines(Day1::DAY, input) {
+        res.extend(script::rotations::<i32>(&line, line.text, &mut left)?);
+    }
+    Ok(res)
+}
+
+/// Rotations of a safe with `dials` dials, each line naming its dial by index
+/// as in `2:L30`, or turning the first dial without one. The rotations after
+/// the index are written as for [`parse`].
+pub fn parse_safe(input: &str, dials: usize) -> Result<Vec<Turn>, ParseError> {
+    let mut res = Vec::new();
+    let mut left = MAX_ROTATIONS;
+    for line in common::lines(Day1::DAY, input) {
+        let code = script::code(line.text);
+        let (dial, text) = match code.split_once(':') {
+            Some((index, text)) => {
+                let index = index.trim();
+                let dial = line.parse::<usize>(index, "dial index")?;
+                if dial >= dials {
+                    let message = format!("no such dial, the safe has {}", dials);
+                    return Err(line.error(index, message));
+                }
+                if text.trim().is_empty() {
+                    return Err(line.error(text, "expected rotation"));
+                }
+                (dial, text)
+            }
+            None => (0, code),
+        };
+        let turns = script::rotations(&line, text, &mut left)?;
+        res.extend(turns.into_iter().map(|amount| Turn::new(dial, amount)));
+    }
+    Ok(res)
+}
+
+/// A rotation amount, which can be turned into one to the left.
+trait Amount: FromStr {
+    fn checked_neg(self) -> Option<Self>;
+}
+
+impl Amount for i32 {
+    fn checked_neg(self) -> Option<Self> {
+        self.checked_neg()
+    }
+}
+
+impl Amount for i128 {
+    fn checked_neg(self) -> Option<Self> {
+        self.checked_neg()
+    }
+}
+
+/// One rotation like `L68` in `text`, a part of `line`. The amount is plain
+/// digits, without a sign.
+fn rotation<T: Amount>(line: &Line, text: &str) -> Result<T, ParseError> {
+    let left = match text.chars().next() {
+        Some('L') => true,
+        Some('R') => false,
+        Some(c) => {
+            let token = &text[..c.len_utf8()];
+            return Err(line.error(token, "expected direction L or R, got"));
+        }
+        None => return Err(line.error(text, "expected rotation")),
+    };
+    let digits = &text[1..];
+    if digits.starts_with(['+', '-']) {
+        return Err(line.error(digits, "invalid rotation amount"));
+    }
+    let amount = line.parse::<T>(digits, "rotation amount")?;
+    if left {
+        amount
+            .checked_neg()
+            .ok_or_else(|| line.error(digits, "rotation amount out of range"))
+    } else {
+        Ok(amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("L68\nX30"),
+            Err(ParseError::new(
+                1,
+                2,
+                1,
+                "X",
+                "expected direction L or R, got"
+            ))
+        );
+        assert_eq!(
+            parse("L68\nR3o"),
+            Err(ParseError::new(1, 2, 2, "3o", "invalid rotation amount"))
+        );
+        assert_eq!(
+            parse("L-5"),
+            Err(ParseError::new(1, 1, 2, "-5", "invalid rotation amount"))
+        );
+        assert_eq!(
+            parse("R+5"),
+            Err(ParseError::new(1, 1, 2, "+5", "invalid rotation amount"))
+        );
+        assert_eq!(
+            parse("L-2147483648"),
+            Err(ParseError::new(
+                1,
+                1,
+                2,
+                "-2147483648",
+                "invalid rotation amount"
+            ))
+        );
+        assert_eq!(parse("L2147483647"), Ok(vec![-2147483647]));
+        assert_eq!(
+            parse("L68\nR1, 2x(L3 R4"),
+            Err(ParseError::new(1, 2, 7, "(", "unclosed repeat block"))
+        );
+    }
+
+    #[test]
+    fn test_parse_script() {
+        let script =
+            "# The example, compacted.\nL68, L30 R48\nL5 R60 L55 L1 L99 # one more\nR14, L82\n";
+        assert_eq!(parse(script), parse(INPUT));
+        assert_eq!(parse("2x(R1 L1) 2x()\n\n"), Ok(vec![1, -1, 1, -1]));
+    }
+
+    #[test]
+    fn test_parse_safe() {
+        assert_eq!(
+            parse_safe("L68\n1:R30\n\n0:L5", 2),
+            Ok(vec![Turn::new(0, -68), Turn::new(1, 30), Turn::new(0, -5)])
+        );
+        assert_eq!(
+            parse_safe("1: 2x(R3), L4  # note: dial 1\n# 0:R5", 2),
+            Ok(vec![Turn::new(1, 3), Turn::new(1, 3), Turn::new(1, -4)])
+        );
+        assert_eq!(
+            parse_safe("L68\n2:R30", 2),
+            Err(ParseError::new(
+                1,
+                2,
+                1,
+                "2",
+                "no such dial, the safe has 2"
+            ))
+        );
+        assert_eq!(
+            parse_safe("1:", 2),
+            Err(ParseError::new(1, 1, 3, "", "expected rotation"))
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        common::examples::assert_example::<Day1>(
+            include_str!("../examples/example.txt"),
+            include_str!("../examples/example.expected"),
+        );
+    }
+
+    #[test]
+    fn test_events() {
+        let timeline: Vec<String> = events(Dial::new(SIZE, 50), &parse(INPUT).unwrap())
+            .map(|event| event.to_string())
+            .collect();
+        assert_eq!(
+            timeline,
+            [
+                "50 L68 -> 82, passed 0 once",
+                "82 L30 -> 52",
+                "52 R48 -> 0, landed on 0",
+                "0 L5 -> 95",
+                "95 R60 -> 55, passed 0 once",
+                "55 L55 -> 0, landed on 0",
+                "0 L1 -> 99",
+                "99 L99 -> 0, landed on 0",
+                "0 R14 -> 14",
+                "14 L82 -> 32, passed 0 once",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_modulus() {
+        // The example on a 10 position dial, where `L68` alone passes 0 six
+        // times.
+        let turns = parse(INPUT).unwrap();
+        assert_eq!(zero_hits(Dial::new(10, 50), &turns), 3);
+        assert_eq!(zero_passes(Dial::new(10, 50), &turns), 45);
+    }
+}
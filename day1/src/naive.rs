@@ -0,0 +1,33 @@
+use common::Reference;
+
+use crate::{Day1, Params, parse};
+
+impl Reference for Day1 {
+    fn naive1(input: &str, params: &Params) -> u64 {
+        let mut dial = params.initial.rem_euclid(params.modulus);
+        let mut zeros = 0;
+        for turn in parse(input).unwrap() {
+            for _ in 0..turn.abs() {
+                dial = (dial + turn.signum() as i64).rem_euclid(params.modulus);
+            }
+            if dial == 0 {
+                zeros += 1;
+            }
+        }
+        zeros
+    }
+
+    fn naive2(input: &str, params: &Params) -> u128 {
+        let mut dial = params.initial.rem_euclid(params.modulus);
+        let mut zeros = 0;
+        for turn in parse(input).unwrap() {
+            for _ in 0..turn.abs() {
+                dial = (dial + turn.signum() as i64).rem_euclid(params.modulus);
+                if dial == 0 {
+                    zeros += 1;
+                }
+            }
+        }
+        zeros
+    }
+}
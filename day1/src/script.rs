@@ -0,0 +1,207 @@
+use common::{Line, ParseError};
+
+use crate::{Amount, rotation};
+
+/// Most rotations one input can hold, so that repeat blocks can't ask for
+/// more memory than there is.
+pub const MAX_ROTATIONS: usize = 10_000_000;
+
+/// Everything on a line before a `#` comment.
+pub(crate) fn code(text: &str) -> &str {
+    text.split_once('#').map_or(text, |(code, _)| code)
+}
+
+/// The rotations written in `text`, a part of `line`, such as
+/// `R10, 3x(L5 R5)  # comment`. Rotations are separated by commas or spaces,
+/// and `3x(...)` repeats its contents three times. Fails once the line would
+/// hold more than `left` rotations, and takes the ones it holds off `left`.
+pub(crate) fn rotations<T>(line: &Line, text: &str, left: &mut usize) -> Result<Vec<T>, ParseError>
+where
+    T: Amount + Clone,
+{
+    let mut parser = Parser {
+        line,
+        rest: code(text),
+        left: *left,
+    };
+    let res = parser.block(None)?;
+    *left = parser.left;
+    Ok(res)
+}
+
+struct Parser<'l, 'a> {
+    line: &'l Line<'a>,
+    rest: &'a str,
+    /// How many more rotations there is room for.
+    left: usize,
+}
+
+impl<'a> Parser<'_, 'a> {
+    /// Rotations up to the end of the text, or up to the `)` matching `open`.
+    fn block<T>(&mut self, open: Option<&'a str>) -> Result<Vec<T>, ParseError>
+    where
+        T: Amount + Clone,
+    {
+        let mut res = Vec::new();
+        loop {
+            self.rest = self
+                .rest
+                .trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+            if self.rest.is_empty() {
+                return match open {
+                    Some(open) => Err(self.line.error(open, "unclosed repeat block")),
+                    None => Ok(res),
+                };
+            }
+            if self.rest.starts_with(')') {
+                let close = self.take(1);
+                return match open {
+                    Some(_) => Ok(res),
+                    None => Err(self.line.error(close, "unmatched")),
+                };
+            }
+
+            let end = self
+                .rest
+                .find(|c: char| c == ',' || c == '(' || c == ')' || c.is_whitespace())
+                .unwrap_or(self.rest.len());
+            let token = self.take(end);
+            match token.strip_suffix('x') {
+                Some(count) if !count.is_empty() && count.bytes().all(|b| b.is_ascii_digit()) => {
+                    let count = self.line.parse::<usize>(count, "repeat count")?;
+                    if !self.rest.starts_with('(') {
+                        return Err(self.line.error(token, "expected ( after repeat count"));
+                    }
+                    let open = self.take(1);
+                    let inner = self.block(Some(open))?;
+                    // The block's rotations were counted once already.
+                    self.left += inner.len();
+                    let total = inner
+                        .len()
+                        .checked_mul(count)
+                        .filter(|&total| total <= self.left)
+                        .ok_or_else(|| self.too_many(token))?;
+                    self.left -= total;
+                    res.reserve(total);
+                    for _ in 0..count {
+                        res.extend_from_slice(&inner);
+                    }
+                }
+                _ => {
+                    let amount = rotation(self.line, token)?;
+                    self.left = self
+                        .left
+                        .checked_sub(1)
+                        .ok_or_else(|| self.too_many(token))?;
+                    res.push(amount);
+                }
+            }
+        }
+    }
+
+    fn too_many(&self, token: &str) -> ParseError {
+        let message = format!("more than {} rotations in the input at", MAX_ROTATIONS);
+        self.line.error(token, message)
+    }
+
+    /// Takes the first `len` bytes off the rest of the text.
+    fn take(&mut self, len: usize) -> &'a str {
+        let (token, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Result<Vec<i32>, ParseError> {
+        let line = Line {
+            day: 1,
+            number: 1,
+            text,
+        };
+        let mut left = MAX_ROTATIONS;
+        rotations(&line, text, &mut left)
+    }
+
+    #[test]
+    fn test_rotations() {
+        assert_eq!(parse("L68"), Ok(vec![-68]));
+        assert_eq!(parse(" R1,L2  R3 ,, L4 "), Ok(vec![1, -2, 3, -4]));
+        assert_eq!(parse("3x(R10 L5)"), Ok(vec![10, -5, 10, -5, 10, -5]));
+        assert_eq!(
+            parse("L1, 2x(R2, 2x(L3)) R4"),
+            Ok(vec![-1, 2, -3, -3, 2, -3, -3, 4])
+        );
+        assert_eq!(parse("0x(R1) L2 # 4x(R1)"), Ok(vec![-2]));
+        assert_eq!(parse("# only a comment"), Ok(vec![]));
+        assert_eq!(parse(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_rotation_errors() {
+        let error = |column, token, message| Err(ParseError::new(1, 1, column, token, message));
+        assert_eq!(
+            parse("R1 U2"),
+            error(4, "U", "expected direction L or R, got")
+        );
+        assert_eq!(
+            parse("R1 3(L2)"),
+            error(4, "3", "expected direction L or R, got")
+        );
+        assert_eq!(
+            parse("2x R1"),
+            error(1, "2x", "expected ( after repeat count")
+        );
+        assert_eq!(parse("2x(R1 L2"), error(3, "(", "unclosed repeat block"));
+        assert_eq!(parse("R1) L2"), error(3, ")", "unmatched"));
+        assert_eq!(
+            parse("2x(R1, Lx)"),
+            error(9, "x", "invalid rotation amount")
+        );
+
+        let too_many = "more than 10000000 rotations in the input at";
+        assert_eq!(
+            parse("R1 4000000000x(R1)"),
+            error(4, "4000000000x", too_many)
+        );
+        assert_eq!(parse("100000x(100000x(R1))"), error(1, "100000x", too_many));
+        assert_eq!(
+            parse("18446744073709551615x(R1 L1)"),
+            error(1, "18446744073709551615x", too_many)
+        );
+        assert_eq!(
+            parse("0x(1000x(10000x(R1)))").map(|turns| turns.len()),
+            Ok(0)
+        );
+        assert_eq!(
+            parse("10000000x(R1)").map(|turns| turns.len()),
+            Ok(MAX_ROTATIONS)
+        );
+    }
+
+    #[test]
+    fn test_budget() {
+        let text = "5x(R1) L2";
+        let line = Line {
+            day: 1,
+            number: 1,
+            text,
+        };
+        let mut left = 7;
+        assert_eq!(
+            rotations::<i32>(&line, text, &mut left).map(|turns| turns.len()),
+            Ok(6)
+        );
+        assert_eq!(left, 1);
+        assert_eq!(
+            rotations::<i32>(&line, text, &mut left),
+            Err(line.error(
+                &text[..2],
+                format!("more than {} rotations in the input at", MAX_ROTATIONS)
+            ))
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use std::{error::Error, fmt, io};
+
+use common::{Line, ParseError, Solution, Wide};
+
+use crate::{Day1, Dial, MAX_ROTATIONS, script};
+
+/// Totals over a log of rotations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub rotations: u64,
+    /// Rotations that leave the dial at 0, part 1's answer.
+    pub hits: u64,
+    /// Times the dial points at 0 during or after a rotation, part 2's answer.
+    pub zeros: Wide,
+}
+
+/// Reading a log failed, or one of its lines isn't a rotation.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => err.fmt(f),
+            StreamError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> Self {
+        StreamError::Parse(err)
+    }
+}
+
+/// Counts the rotations read from `reader` as they come, one line at a time,
+/// so a log of any length needs only one line's rotations in memory. Lines
+/// are written as for [`parse`](crate::parse), each with room for
+/// [`MAX_ROTATIONS`], and amounts can be as large as an `i128` holds.
+pub fn count_stream(mut dial: Dial, mut reader: impl io::BufRead) -> Result<Counts, StreamError> {
+    let mut counts = Counts::default();
+    let mut buf = String::new();
+    let mut number = 0;
+
+    loop {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            break;
+        }
+        number += 1;
+        // Line endings as `str::lines` takes them off.
+        let text = buf.strip_suffix('\n').unwrap_or(&buf);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+
+        let line = Line {
+            day: Day1::DAY,
+            number,
+            text,
+        };
+        let mut left = MAX_ROTATIONS;
+        for amount in script::rotations::<i128>(&line, text, &mut left)? {
+            let event = dial.rotate(amount);
+            counts.rotations += 1;
+            counts.hits += u64::from(event.landed);
+            counts.zeros += Wide::from(event.zeros());
+        }
+    }
+
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, Read};
+
+    use super::*;
+
+    #[test]
+    fn test_count_stream() {
+        let input = "L68\r\nL30\nR48\n\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+        let counts = count_stream(Dial::new(100, 50), input.as_bytes()).unwrap();
+        assert_eq!(counts.rotations, 10);
+        assert_eq!(counts.hits, 3);
+        assert_eq!(counts.zeros, 6);
+
+        // Three of the largest rotations pass 0 more often than a `u128` counts.
+        let huge = format!("R{}\n", i128::MAX).repeat(3);
+        let counts = count_stream(Dial::new(1, 0), huge.as_bytes()).unwrap();
+        assert_eq!(counts.hits, 3);
+        assert_eq!(
+            counts.zeros,
+            Wide::from(i128::MAX as u128)
+                + Wide::from(i128::MAX as u128)
+                + Wide::from(i128::MAX as u128)
+        );
+    }
+
+    #[test]
+    fn test_stream_script() {
+        let input = "# The example\nL68, L30 R48\n\nL5 R60 L55 L1 L99 # c\nR14, L82\n2x(R100)\n";
+        let counts = count_stream(Dial::new(100, 50), input.as_bytes()).unwrap();
+        assert_eq!(counts.rotations, 12);
+        assert_eq!(counts.hits, 3);
+        assert_eq!(counts.zeros, 8);
+    }
+
+    #[test]
+    fn test_stream_errors() {
+        let err = count_stream(Dial::new(100, 50), "L68\n\nR3o\n".as_bytes()).unwrap_err();
+        assert!(matches!(
+            err,
+            StreamError::Parse(err) if err == ParseError::new(1, 3, 2, "3o", "invalid rotation amount")
+        ));
+
+        struct Broken;
+        impl Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+        let err = count_stream(Dial::new(100, 50), BufReader::new(Broken)).unwrap_err();
+        assert_eq!(err.to_string(), "disk on fire");
+    }
+}
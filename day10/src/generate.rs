@@ -0,0 +1,54 @@
+use common::{Generator, Rng};
+
+use crate::Day10;
+
+/// `size` machines with up to five lights and five buttons, whose targets come
+/// from random presses so both parts have an answer.
+impl Generator for Day10 {
+    fn generate(rng: &mut Rng, size: usize, _: &()) -> String {
+        let mut res = String::new();
+        for _ in 0..size.max(1) {
+            let lights = rng.range(1..=5) as usize;
+            let buttons: Vec<Vec<usize>> = (0..rng.range(1..=5))
+                .map(|_| {
+                    let mut button: Vec<usize> = (0..lights).filter(|_| rng.chance(0.4)).collect();
+                    if button.is_empty() {
+                        button.push(rng.index(lights));
+                    }
+                    button
+                })
+                .collect();
+
+            let mut on = vec![false; lights];
+            let mut joltages = vec![0; lights];
+            for button in &buttons {
+                let presses = rng.range(0..=2);
+                for &i in button {
+                    joltages[i] += presses;
+                }
+                if rng.chance(0.5) {
+                    for &i in button {
+                        on[i] = !on[i];
+                    }
+                }
+            }
+
+            let lights: String = on.iter().map(|&on| if on { '#' } else { '.' }).collect();
+            let buttons: Vec<String> = buttons
+                .iter()
+                .map(|button| {
+                    let list: Vec<String> = button.iter().map(usize::to_string).collect();
+                    format!("({})", list.join(","))
+                })
+                .collect();
+            let joltages: Vec<String> = joltages.iter().map(u64::to_string).collect();
+            res.push_str(&format!(
+                "[{}] {} {{{}}}\n",
+                lights,
+                buttons.join(" "),
+                joltages.join(",")
+            ));
+        }
+        res
+    }
+}
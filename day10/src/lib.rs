@@ -0,0 +1,377 @@
+use std::str::FromStr;
+
+use common::{Line, ParseError, Solution};
+use z3::{Params, Solver, ast::Int};
+
+mod generate;
+mod naive;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = (Vec<Indicator>, Vec<IndicatorJoltage>);
+    type Answer1 = u32;
+    type Answer2 = u64;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(Vec<Indicator>, Vec<IndicatorJoltage>), ParseError> {
+        Ok((parse1(input)?, parse2(input)?))
+    }
+
+    fn part1((indicators, _): &(Vec<Indicator>, Vec<IndicatorJoltage>), _: &()) -> u32 {
+        part1(indicators)
+    }
+
+    fn part2((_, indicators): &(Vec<Indicator>, Vec<IndicatorJoltage>), _: &()) -> u64 {
+        part2(indicators)
+    }
+}
+
+/// Fewest presses in total to reach every machine's joltages. Panics if a
+/// machine can't reach them.
+pub fn part2(indicators: &[IndicatorJoltage]) -> u64 {
+    let mut total = 0;
+    for (n, indicator) in indicators.iter().enumerate() {
+        let solver = Solver::new();
+        // z3 can spend a long time inside a single check, where no
+        // checkpoint runs, so it gets the time that's left as its own limit,
+        // rounded up so that it only gives up once the deadline has passed.
+        if let Some(left) = common::cancel::remaining() {
+            let mut params = Params::new();
+            params.set_u32(
+                "timeout",
+                (left.as_millis() + 1).min(u32::MAX as u128) as u32,
+            );
+            solver.set_params(&params);
+        }
+
+        let presses: Vec<_> = indicator
+            .buttons
+            .iter()
+            .enumerate()
+            .map(|(i, _)| Int::new_const(format!("b{}", i)))
+            .collect();
+
+        for p in &presses {
+            solver.assert(p.ge(Int::from_u64(0)));
+        }
+
+        for (i, value) in indicator.joltages.iter().enumerate() {
+            let mut items = Vec::new();
+            for (j, button) in indicator.buttons.iter().enumerate() {
+                if button.contains(&i) {
+                    items.push(&presses[j]);
+                }
+            }
+            solver.assert(
+                items
+                    .iter()
+                    .fold(Int::from_u64(0), |sum, i| sum + *i)
+                    .eq(Int::from_u64(*value as u64)),
+            );
+        }
+
+        let mut min_presses = None;
+
+        for solution in solver.solutions(presses, false).take(1000) {
+            common::cancel::checkpoint();
+            let sum: u64 = solution.iter().map(Int::as_u64).map(Option::unwrap).sum();
+            if min_presses.is_none_or(|min| sum < min) {
+                min_presses = Some(sum);
+            }
+        }
+        // z3 gives up without an error when it runs out of time, which would
+        // look like a machine without solutions.
+        common::cancel::checkpoint();
+        match min_presses {
+            Some(presses) => total += presses,
+            None => panic!("machine {} has no solution", n + 1),
+        }
+    }
+    total
+}
+
+/// Buttons and joltage requirements of each machine.
+pub fn parse2(input: &str) -> Result<Vec<IndicatorJoltage>, ParseError> {
+    let mut res = Vec::new();
+
+    for line in common::lines(Day10::DAY, input) {
+        let (_, buttons, joltages) = split_line(&line)?;
+        let joltages = parse_list(&line, joltages, '{', '}', "joltage")?;
+
+        let mut counters = Vec::new();
+        for s in buttons {
+            let button: Vec<usize> = parse_list(&line, s, '(', ')', "button")?;
+            if button.iter().any(|&i| i >= joltages.len()) {
+                return Err(line.error(s, "button index out of range"));
+            }
+            counters.push(button);
+        }
+
+        res.push(IndicatorJoltage::new(counters, joltages));
+    }
+
+    Ok(res)
+}
+
+/// A machine's buttons, as the counters each one increases, and the
+/// joltage each counter must reach.
+#[derive(Debug, PartialEq, Clone)]
+pub struct IndicatorJoltage {
+    pub buttons: Vec<Vec<usize>>,
+    pub joltages: Vec<usize>,
+}
+
+impl IndicatorJoltage {
+    pub fn new(buttons: Vec<Vec<usize>>, joltages: Vec<usize>) -> Self {
+        Self { buttons, joltages }
+    }
+}
+
+/// Fewest presses in total to light every machine's desired lights.
+pub fn part1(indicators: &[Indicator]) -> u32 {
+    let mut total: u32 = 0;
+
+    for indicator in indicators {
+        let count = indicator.buttons.len();
+        let mut min_toggles = indicator.buttons.len();
+
+        for n in 0..1_u64 << count {
+            if n % 4096 == 0 {
+                common::cancel::checkpoint();
+            }
+            let bits: Vec<u8> = (0..count).map(|i| ((n >> i) & 1) as u8).collect();
+            let bits_count = bits.iter().filter(|&&b| b == 1).count();
+            let state = bits
+                .iter()
+                .enumerate()
+                .map(|(i, &k)| k as u32 * indicator.buttons[i])
+                .fold(0, |acc, x| acc ^ x);
+
+            if state == indicator.desired && bits_count < min_toggles {
+                min_toggles = bits_count;
+            }
+        }
+        total += min_toggles as u32;
+    }
+
+    total
+}
+
+/// Light diagrams and buttons of each machine, as bit masks.
+pub fn parse1(input: &str) -> Result<Vec<Indicator>, ParseError> {
+    let mut res = Vec::new();
+
+    for line in common::lines(Day10::DAY, input) {
+        let (desired, buttons, _) = split_line(&line)?;
+        let lights = desired
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or_else(|| line.error(desired, "expected lights in [...]"))?;
+        if lights.len() > u32::BITS as usize {
+            let message = format!("more than {} lights", u32::BITS);
+            return Err(line.error(lights, message));
+        }
+
+        let mut desired = 0;
+        for (i, c) in lights.char_indices() {
+            match c {
+                '.' => {}
+                '#' => desired |= 1 << i,
+                _ => return Err(line.error(&lights[i..i + c.len_utf8()], "invalid light")),
+            }
+        }
+
+        let mut masks = Vec::new();
+        for (n, s) in buttons.into_iter().enumerate() {
+            // Part 1 tries every subset of the buttons.
+            if n == u32::BITS as usize {
+                let message = format!("more than {} buttons at", u32::BITS);
+                return Err(line.error(s, message));
+            }
+            let mut mask = 0;
+            for i in parse_list::<u32>(&line, s, '(', ')', "button")? {
+                if i as usize >= lights.len() {
+                    return Err(line.error(s, "button index out of range"));
+                }
+                mask |= 1 << i;
+            }
+            masks.push(mask);
+        }
+
+        res.push(Indicator::new(desired, masks));
+    }
+
+    Ok(res)
+}
+
+/// Splits a machine description into its lights, buttons and joltages tokens.
+fn split_line<'a>(line: &Line<'a>) -> Result<(&'a str, Vec<&'a str>, &'a str), ParseError> {
+    let parts: Vec<&str> = line.text.split_whitespace().collect();
+    match parts.as_slice() {
+        [lights, buttons @ .., joltages] => Ok((lights, buttons.to_vec(), joltages)),
+        _ => Err(line.error(line.text, "expected lights, buttons and joltages")),
+    }
+}
+
+fn parse_list<T: FromStr>(
+    line: &Line,
+    token: &str,
+    open: char,
+    close: char,
+    what: &str,
+) -> Result<Vec<T>, ParseError> {
+    token
+        .strip_prefix(open)
+        .and_then(|s| s.strip_suffix(close))
+        .ok_or_else(|| line.error(token, format!("expected {} in {}...{}", what, open, close)))?
+        .split(',')
+        .map(|s| line.parse::<T>(s, what))
+        .collect()
+}
+
+/// A machine's desired lights and the lights each button toggles, as bit
+/// masks with light `i` in bit `i`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Indicator {
+    pub desired: u32,
+    pub buttons: Vec<u32>,
+}
+
+impl Indicator {
+    pub fn new(desired: u32, buttons: Vec<u32>) -> Self {
+        Self { desired, buttons }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_parse1() {
+        assert_eq!(
+            parse1("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"),
+            Ok(vec![Indicator::new(
+                6,
+                vec![8, 10, 4, 12, 5, 3].into_iter().collect(),
+            )]),
+        );
+    }
+
+    #[test]
+    fn test_parse2() {
+        assert_eq!(
+            parse2("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"),
+            Ok(vec![IndicatorJoltage::new(
+                vec![
+                    vec![3],
+                    vec![1, 3],
+                    vec![2],
+                    vec![2, 3],
+                    vec![0, 2],
+                    vec![0, 1],
+                ],
+                vec![3, 5, 4, 7],
+            )]),
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse1("[.#x.] (3) {3}"),
+            Err(ParseError::new(10, 1, 4, "x", "invalid light"))
+        );
+        assert_eq!(
+            parse1("[.##.] (3) (1,4) {3}"),
+            Err(ParseError::new(
+                10,
+                1,
+                12,
+                "(1,4)",
+                "button index out of range"
+            ))
+        );
+        assert_eq!(
+            parse1(&format!("[{}] (0) {{3}}", ".".repeat(33))),
+            Err(ParseError::new(
+                10,
+                1,
+                2,
+                ".".repeat(33),
+                "more than 32 lights"
+            ))
+        );
+        assert_eq!(
+            parse1(&format!("[{}] (31,31) {{3}}", "#".repeat(32))),
+            Ok(vec![Indicator::new(u32::MAX, vec![1 << 31])])
+        );
+        assert_eq!(
+            parse1(&format!("[#] {}{{1}}", "(0) ".repeat(33))),
+            Err(ParseError::new(
+                10,
+                1,
+                133,
+                "(0)",
+                "more than 32 buttons at"
+            ))
+        );
+        assert_eq!(
+            parse1(&format!("[#] {}{{1}}", "(0) ".repeat(32)))
+                .map(|machines| machines[0].buttons.len()),
+            Ok(32)
+        );
+        assert_eq!(
+            parse2("[.##.] (3) (1;3) {3,5,4,7}"),
+            Err(ParseError::new(10, 1, 13, "1;3", "invalid button"))
+        );
+        assert_eq!(
+            parse2("[.##.] (3) (1,4) {3,5,4,7}"),
+            Err(ParseError::new(
+                10,
+                1,
+                12,
+                "(1,4)",
+                "button index out of range"
+            ))
+        );
+        assert_eq!(
+            parse2("[.##.] (3) 3,5"),
+            Err(ParseError::new(
+                10,
+                1,
+                12,
+                "3,5",
+                "expected joltage in {...}"
+            ))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "machine 2 has no solution")]
+    fn test_part2_unsolvable() {
+        part2(&parse2("[#] (0) {1}\n[.#] (0) {1,2}").unwrap());
+    }
+
+    #[test]
+    fn test_part2_timeout() {
+        let machines = parse2("[#] (0) {1}").unwrap();
+        let res = std::panic::catch_unwind(|| {
+            common::cancel::with_timeout(Some(Duration::ZERO), || part2(&machines))
+        });
+        assert!(res.unwrap_err().is::<common::cancel::Cancelled>());
+    }
+
+    #[test]
+    fn test_example() {
+        common::examples::assert_example::<Day10>(
+            include_str!("../examples/example.txt"),
+            include_str!("../examples/example.expected"),
+        );
+    }
+}
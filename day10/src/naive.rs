@@ -0,0 +1,83 @@
+use std::collections::{HashSet, VecDeque};
+
+use common::Reference;
+
+use crate::{Day10, parse1, parse2};
+
+impl Reference for Day10 {
+    fn naive1(input: &str, _: &()) -> u32 {
+        parse1(input)
+            .unwrap()
+            .iter()
+            .map(|machine| {
+                // Breadth-first over light states, one press at a time.
+                let mut seen = HashSet::from([0]);
+                let mut queue = VecDeque::from([(0_u32, 0)]);
+                while let Some((state, presses)) = queue.pop_front() {
+                    if state == machine.desired {
+                        return presses;
+                    }
+                    for &button in &machine.buttons {
+                        if seen.insert(state ^ button) {
+                            queue.push_back((state ^ button, presses + 1));
+                        }
+                    }
+                }
+                panic!("lights can't be reached")
+            })
+            .sum()
+    }
+
+    fn naive2(input: &str, _: &()) -> u64 {
+        parse2(input)
+            .unwrap()
+            .iter()
+            .map(|machine| {
+                fewest_presses(&machine.buttons, &mut machine.joltages.clone())
+                    .expect("joltages can't be reached")
+            })
+            .sum()
+    }
+
+    fn is_valid(input: &str, _: &()) -> bool {
+        let lights = parse1(input).unwrap();
+        let joltages = parse2(input).unwrap();
+        lights.iter().all(|machine| {
+            (0..1_u32 << machine.buttons.len()).any(|n| {
+                let state = (0..machine.buttons.len())
+                    .filter(|i| n >> i & 1 == 1)
+                    .fold(0, |state, i| state ^ machine.buttons[i]);
+                state == machine.desired
+            })
+        }) && joltages.iter().all(|machine| {
+            machine
+                .buttons
+                .iter()
+                .flatten()
+                .all(|&i| i < machine.joltages.len())
+                && fewest_presses(&machine.buttons, &mut machine.joltages.clone()).is_some()
+        })
+    }
+}
+
+/// Fewest presses of `buttons` that bring every joltage in `left` down to
+/// zero, trying every number of presses of each button in turn.
+fn fewest_presses(buttons: &[Vec<usize>], left: &mut [usize]) -> Option<u64> {
+    let Some((button, rest)) = buttons.split_first() else {
+        return left.iter().all(|&j| j == 0).then_some(0);
+    };
+    let max = button.iter().map(|&i| left[i]).min().unwrap_or(0);
+    let mut best = None;
+    for presses in 0..=max {
+        for &i in button {
+            left[i] -= presses;
+        }
+        if let Some(n) = fewest_presses(rest, left) {
+            best = Some(best.map_or(n + presses as u64, |b: u64| b.min(n + presses as u64)));
+        }
+        for &i in button {
+            left[i] += presses;
+        }
+    }
+    best
+}
@@ -0,0 +1,43 @@
+use std::collections::BTreeSet;
+
+use common::{Generator, Rng};
+
+use crate::{Day11, Params};
+
+/// How far ahead in the order a device's extra outputs reach, so path counts
+/// stay manageable as the graph grows.
+const REACH: usize = 8;
+
+/// A DAG of `size` devices besides the named ones, with the server first,
+/// `out` last and every device reachable from the server.
+impl Generator for Day11 {
+    fn generate(rng: &mut Rng, size: usize, params: &Params) -> String {
+        let mut nodes: Vec<String> = (0..size).map(|i| format!("n{:02}", i)).collect();
+        nodes.extend([params.you.clone(), params.dac.clone(), params.fft.clone()]);
+        rng.shuffle(&mut nodes);
+        nodes.insert(0, params.svr.clone());
+        nodes.push(params.out.clone());
+
+        let n = nodes.len();
+        let mut edges: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+        for i in 1..n {
+            edges[rng.range(i.saturating_sub(REACH) as u64..=i as u64 - 1) as usize].insert(i);
+        }
+        for (i, outputs) in edges.iter_mut().enumerate().take(n - 1) {
+            outputs.insert(rng.range(i as u64 + 1..=(i + REACH).min(n - 1) as u64) as usize);
+            for j in i + 1..(i + 1 + REACH).min(n) {
+                if rng.chance(0.3) {
+                    outputs.insert(j);
+                }
+            }
+        }
+
+        let mut res = String::new();
+        for (i, outputs) in edges.iter().enumerate().take(n - 1) {
+            let mut outputs: Vec<&str> = outputs.iter().map(|&j| nodes[j].as_str()).collect();
+            outputs.sort();
+            res.push_str(&format!("{}: {}\n", nodes[i], outputs.join(" ")));
+        }
+        res
+    }
+}
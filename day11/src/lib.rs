@@ -0,0 +1,307 @@
+use common::{ParseError, Solution, Wide, params};
+use graph::{Graph, NodeId};
+
+mod generate;
+mod naive;
+
+pub struct Day11;
+
+/// The devices, with an edge from each device to each of its outputs.
+pub type Devices<'a> = Graph<&'a str>;
+
+/// Node names of the puzzle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Params {
+    pub you: String,
+    pub svr: String,
+    pub dac: String,
+    pub fft: String,
+    pub out: String,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            you: "you".to_owned(),
+            svr: "svr".to_owned(),
+            dac: "dac".to_owned(),
+            fft: "fft".to_owned(),
+            out: "out".to_owned(),
+        }
+    }
+}
+
+impl common::Params for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "you" => self.you = value.to_owned(),
+            "svr" => self.svr = value.to_owned(),
+            "dac" => self.dac = value.to_owned(),
+            "fft" => self.fft = value.to_owned(),
+            "out" => self.out = value.to_owned(),
+            _ => return Err(params::unknown(name)),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = Devices<'a>;
+    type Answer1 = u64;
+    type Answer2 = Wide;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Devices<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(graph: &Devices, params: &Params) -> u64 {
+        part1(graph, params)
+    }
+
+    fn part2(graph: &Devices, params: &Params) -> Wide {
+        part2(graph, params)
+    }
+
+    fn check(graph: &Devices, params: &Params, part: u8) -> Result<(), String> {
+        let names = match part {
+            1 => vec![("you", &params.you), ("out", &params.out)],
+            _ => vec![
+                ("svr", &params.svr),
+                ("dac", &params.dac),
+                ("fft", &params.fft),
+                ("out", &params.out),
+            ],
+        };
+        match names
+            .iter()
+            .find(|(_, name)| graph.id(&name.as_str()).is_none())
+        {
+            Some((param, name)) => Err(format!("no device {:?} for parameter {}", name, param)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Number of paths from the server to `out` through both `dac` and `fft`,
+/// which can be more than any fixed width integer holds.
+pub fn part2(graph: &Devices, params: &Params) -> Wide {
+    let [svr, dac, fft, out] =
+        [&params.svr, &params.dac, &params.fft, &params.out].map(|name| device(graph, name));
+
+    let Some(order) = graph.toposort() else {
+        // With a cycle only simple paths count, and there's no shortcut for
+        // those.
+        let paths = find_all_paths(graph, svr, out, &[], &[]);
+        let count = paths
+            .iter()
+            .filter(|path| path.contains(&dac) && path.contains(&fft))
+            .count();
+        return Wide::from(count);
+    };
+
+    // Without cycles a path through both visits them in one order or the
+    // other, and splits into independent paths between them.
+    let count = |from, to| count_paths(graph, &order, from, to);
+    count(svr, dac) * count(dac, fft) * count(fft, out)
+        + count(svr, fft) * count(fft, dac) * count(dac, out)
+}
+
+/// Number of paths from `start` to `end` in a graph without cycles, given its
+/// nodes in topological `order`.
+pub fn count_paths(graph: &Devices, order: &[NodeId], start: NodeId, end: NodeId) -> Wide {
+    let mut counts = vec![Wide::ZERO; graph.len()];
+    counts[end.index()] = Wide::ONE;
+    for &node in order.iter().rev() {
+        common::cancel::checkpoint();
+        if node != end {
+            counts[node.index()] = graph
+                .successors(node)
+                .iter()
+                .map(|next| counts[next.index()].clone())
+                .sum();
+        }
+    }
+    std::mem::take(&mut counts[start.index()])
+}
+
+/// Number of paths from `you` to `out`.
+pub fn part1(graph: &Devices, params: &Params) -> u64 {
+    let you = device(graph, &params.you);
+    let out = device(graph, &params.out);
+    find_all_paths(graph, you, out, &[], &[]).len() as u64
+}
+
+/// The id of the device named `name`, which must be in the graph, as
+/// [`Day11::check`](Solution::check) makes sure of for the named devices.
+pub fn device(graph: &Devices, name: &str) -> NodeId {
+    graph
+        .id(&name)
+        .unwrap_or_else(|| panic!("no device {:?}", name))
+}
+
+/// Every path from `start` to `end` that continues `path` and passes no
+/// device of `stops`.
+pub fn find_all_paths(
+    graph: &Devices,
+    start: NodeId,
+    end: NodeId,
+    path: &[NodeId],
+    stops: &[NodeId],
+) -> Vec<Vec<NodeId>> {
+    common::cancel::checkpoint();
+    let mut path = path.to_vec();
+    path.push(start);
+
+    if start == end {
+        return vec![path];
+    }
+
+    if stops.contains(&start) {
+        return vec![];
+    }
+
+    let mut all_paths = Vec::new();
+    for neighbor in graph.successors(start) {
+        if !path.contains(neighbor) {
+            let paths = find_all_paths(graph, *neighbor, end, &path, stops);
+            all_paths.extend(paths);
+        }
+    }
+    all_paths
+}
+
+/// Outputs of each device, including devices without outputs.
+pub fn parse(input: &str) -> Result<Devices<'_>, ParseError> {
+    let mut graph = Graph::new();
+    for line in common::lines(Day11::DAY, input) {
+        let (node, outputs) = line
+            .text
+            .split_once(": ")
+            .ok_or_else(|| line.error(line.text, "expected \"node: outputs\""))?;
+        if node.is_empty() || node.contains(char::is_whitespace) {
+            return Err(line.error(node, "invalid node name"));
+        }
+        let node = graph.add_node(node);
+        for out in outputs.split_whitespace() {
+            let out = graph.add_node(out);
+            graph.add_edge(node, out);
+        }
+    }
+    Ok(graph)
+}
+
+/// The graph in DOT format, with the devices of each `highlight` list in its
+/// colour.
+pub fn graphviz(graph: &Devices, highlight: &[(&[&str], &str)]) -> String {
+    let mut res = String::new();
+
+    res.push_str("digraph Components {\n");
+
+    for n in graph.nodes() {
+        let mut style = String::new();
+        for (part, color) in highlight {
+            if part.contains(&graph[n]) {
+                style.push_str(&format!("[color={}]", color));
+                break;
+            }
+        }
+
+        res.push_str(&format!("\"{}\" {};\n", graph[n], style));
+    }
+
+    for (from, to) in graph.edges() {
+        res.push_str(&format!("\"{}\" -> \"{}\";\n", graph[from], graph[to]));
+    }
+
+    res.push_str("}\n");
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let graph = parse("a: b c\nc: b").unwrap();
+        let outputs: HashMap<&str, Vec<&str>> = graph
+            .nodes()
+            .map(|node| {
+                let outputs = graph.successors(node).iter().map(|&n| graph[n]);
+                (graph[node], outputs.collect())
+            })
+            .collect();
+        assert_eq!(
+            outputs,
+            HashMap::from([("a", vec!["b", "c"]), ("b", vec![]), ("c", vec!["b"])]),
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("a: b c\nb c"),
+            Err(ParseError::new(
+                11,
+                2,
+                1,
+                "b c",
+                "expected \"node: outputs\""
+            ))
+        );
+        assert_eq!(
+            parse("a: b c\n: c"),
+            Err(ParseError::new(11, 2, 1, "", "invalid node name"))
+        );
+    }
+
+    const INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn test_check() {
+        let graph = parse(INPUT).unwrap();
+        let mut params = Params::default();
+        assert_eq!(Day11::check(&graph, &params, 1), Ok(()));
+        assert_eq!(
+            Day11::check(&graph, &params, 2),
+            Err("no device \"svr\" for parameter svr".to_owned())
+        );
+        params.you = "xyz".to_owned();
+        assert_eq!(
+            Day11::check(&graph, &params, 1),
+            Err("no device \"xyz\" for parameter you".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        common::examples::assert_example::<Day11>(
+            include_str!("../examples/example.txt"),
+            include_str!("../examples/example.expected"),
+        );
+    }
+
+    #[test]
+    fn test_example2() {
+        common::examples::assert_example::<Day11>(
+            include_str!("../examples/example2.txt"),
+            include_str!("../examples/example2.expected"),
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        // dac and fft are on different branches, so no path visits both.
+        let input = "svr: n03 you\nn03: fft n07\nn07: dac\nfft: out";
+        assert_eq!(part2(&parse(input).unwrap(), &Params::default()), 0);
+        // A cycle that a path can't go round twice.
+        let input = "svr: dac\ndac: fft\nfft: dac out";
+        assert_eq!(part2(&parse(input).unwrap(), &Params::default()), 1);
+    }
+}
@@ -0,0 +1,67 @@
+use common::{Reference, Wide};
+use graph::NodeId;
+
+use crate::{Day11, Devices, Params, device, parse};
+
+impl Reference for Day11 {
+    fn naive1(input: &str, params: &Params) -> u64 {
+        let graph = parse(input).unwrap();
+        let mut count = 0;
+        let (you, out) = (device(&graph, &params.you), device(&graph, &params.out));
+        walk(&graph, you, out, &mut vec![], &mut |_| count += 1);
+        count
+    }
+
+    fn naive2(input: &str, params: &Params) -> Wide {
+        let graph = parse(input).unwrap();
+        let [svr, dac, fft, out] =
+            [&params.svr, &params.dac, &params.fft, &params.out].map(|name| device(&graph, name));
+        let mut count: usize = 0;
+        walk(&graph, svr, out, &mut vec![], &mut |path| {
+            if path.contains(&dac) && path.contains(&fft) {
+                count += 1;
+            }
+        });
+        Wide::from(count)
+    }
+
+    fn is_valid(input: &str, params: &Params) -> bool {
+        let graph = parse(input).unwrap();
+        let named = [
+            &params.you,
+            &params.svr,
+            &params.dac,
+            &params.fft,
+            &params.out,
+        ];
+        if !named.iter().all(|name| graph.id(&name.as_str()).is_some()) {
+            return false;
+        }
+
+        // Every node is reachable from the server, and there are no cycles.
+        let svr = device(&graph, &params.svr);
+        graph.dfs(svr).len() == graph.len() && graph.is_acyclic()
+    }
+}
+
+/// Calls `found` with every path from `node` to `end`.
+fn walk(
+    graph: &Devices,
+    node: NodeId,
+    end: NodeId,
+    path: &mut Vec<NodeId>,
+    found: &mut impl FnMut(&[NodeId]),
+) {
+    if path.contains(&node) {
+        return;
+    }
+    path.push(node);
+    if node == end {
+        found(path);
+    } else {
+        for &next in graph.successors(node) {
+            walk(graph, next, end, path, found);
+        }
+    }
+    path.pop();
+}
@@ -0,0 +1,22 @@
+use common::{Generator, Rng};
+
+use crate::Day2;
+
+/// `size` ranges of up to 300 IDs each, one in ten of them starting at 0.
+impl Generator for Day2 {
+    fn generate(rng: &mut Rng, size: usize, _: &()) -> String {
+        let ranges: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let digits = rng.range(1..=7) as u32;
+                let left = if rng.chance(0.1) {
+                    0
+                } else {
+                    rng.range(1..=10_u64.pow(digits))
+                };
+                let right = left + rng.range(0..=300);
+                format!("{}-{}", left, right)
+            })
+            .collect();
+        format!("{}\n", ranges.join(","))
+    }
+}
@@ -0,0 +1,244 @@
+use std::{cmp, collections::HashSet};
+
+use common::{ParseError, Solution};
+
+mod generate;
+mod naive;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<(u64, u64)>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(ranges: &Vec<(u64, u64)>, _: &()) -> u128 {
+        part1(ranges)
+    }
+
+    fn part2(ranges: &Vec<(u64, u64)>, _: &()) -> u128 {
+        part2(ranges)
+    }
+}
+
+/// Sum of the IDs in the ranges made of a digit sequence repeated at least
+/// twice. Summed in `u128`, which no realistic number of IDs overflows.
+pub fn part2(ranges: &[(u64, u64)]) -> u128 {
+    let mut sum = 0;
+    for range in ranges {
+        let mut set = HashSet::new();
+        // 0 has no digits, but it can't repeat any either.
+        let digits_left = digits_num(range.0).max(1);
+        let digits_right = digits_num(range.1);
+        for digits_count in digits_left..=digits_right {
+            let divs = divisors(digits_count);
+            for divisor in divs {
+                // We need at least 2 repetitions.
+                if divisor == digits_count {
+                    continue;
+                }
+                let repeat_count = digits_count / divisor;
+
+                let left = cmp::max(
+                    10_u64.pow(divisor - 1),
+                    range.0 / 10_u64.pow(digits_count - divisor),
+                );
+                let right = cmp::min(
+                    10_u64.pow(divisor) - 1,
+                    range.1 / 10_u64.pow(digits_count - divisor),
+                );
+
+                for d in left..=right {
+                    if let Some(n) = repeat_num(d, divisor, repeat_count)
+                        && n >= range.0
+                        && n <= range.1
+                    {
+                        set.insert(n);
+                    }
+                }
+            }
+        }
+
+        for n in set {
+            sum += n as u128;
+        }
+    }
+    sum
+}
+
+/// Sum of the IDs in the ranges made of a digit sequence repeated exactly
+/// twice.
+pub fn part1(ranges: &[(u64, u64)]) -> u128 {
+    let mut sum = 0;
+
+    for range in ranges {
+        // 0 has no digits, but it can't repeat any either.
+        let digits_left = digits_num(range.0).max(1);
+        let digits_right = digits_num(range.1);
+
+        for i in digits_left..=digits_right {
+            // Skip numbers with odd number of digits.
+            if i % 2 != 0 {
+                continue;
+            }
+            let d = i / 2;
+
+            let left = cmp::max(range.0, 10_u64.pow(i - 1)) / 10_u64.pow(d);
+            let largest = repeat_num(9, 1, i).unwrap_or(u64::MAX);
+            let right = cmp::min(range.1, largest) / 10_u64.pow(d);
+
+            for half in left..=right {
+                if let Some(n) = repeat_num(half, d, 2)
+                    && n >= range.0
+                    && n <= range.1
+                {
+                    sum += n as u128;
+                }
+            }
+        }
+    }
+
+    sum
+}
+
+/// Divisors of `num` in increasing order.
+pub fn divisors(num: u32) -> Vec<u32> {
+    let mut divs = Vec::new();
+    let mut i = 1;
+    while i * i <= num {
+        if num.is_multiple_of(i) {
+            divs.push(i);
+            if i != num / i {
+                divs.push(num / i);
+            }
+        }
+        i += 1;
+    }
+    divs.sort();
+    divs
+}
+
+/// `num`, which has `digits` digits, written `count` times in a row, or
+/// `None` if that doesn't fit in a `u64`.
+pub fn repeat_num(num: u64, digits: u32, count: u32) -> Option<u64> {
+    let mut res = num;
+    for _ in 0..count - 1 {
+        res = res
+            .checked_mul(10_u64.checked_pow(digits)?)?
+            .checked_add(num)?;
+    }
+    Some(res)
+}
+
+/// Number of decimal digits of `num`, 0 for 0.
+pub fn digits_num(num: u64) -> u32 {
+    let mut n = num;
+    let mut res = 0;
+
+    while n > 0 {
+        n /= 10;
+        res += 1;
+    }
+
+    res
+}
+
+/// Inclusive ID ranges.
+pub fn parse(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    let mut ranges = Vec::new();
+
+    for line in common::lines(Day2::DAY, input) {
+        if line.text.is_empty() {
+            continue;
+        }
+
+        for range_str in line.text.split(',') {
+            let (left_str, right_str) = range_str
+                .split_once('-')
+                .ok_or_else(|| line.error(range_str, "invalid range"))?;
+
+            let left = line.parse::<u64>(left_str, "left")?;
+            let right = line.parse::<u64>(right_str, "right")?;
+
+            ranges.push((left, right));
+        }
+    }
+
+    Ok(ranges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digits_num() {
+        assert_eq!(digits_num(1), 1);
+        assert_eq!(digits_num(10), 2);
+        assert_eq!(digits_num(99), 2);
+        assert_eq!(digits_num(123123), 6);
+    }
+
+    #[test]
+    fn test_repeat_num() {
+        assert_eq!(repeat_num(9, 1, 1), Some(9));
+        assert_eq!(repeat_num(9, 1, 2), Some(99));
+        assert_eq!(repeat_num(9, 1, 10), Some(9999999999));
+        assert_eq!(repeat_num(123, 3, 2), Some(123123));
+        assert_eq!(repeat_num(12, 2, 3), Some(121212));
+        assert_eq!(repeat_num(9, 1, 20), None);
+    }
+
+    #[test]
+    fn test_divisors() {
+        assert_eq!(divisors(10), vec![1, 2, 5, 10]);
+        assert_eq!(divisors(11), vec![1, 11]);
+        assert_eq!(divisors(36), vec![1, 2, 3, 4, 6, 9, 12, 18, 36]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("11-22,95_115"),
+            Err(ParseError::new(2, 1, 7, "95_115", "invalid range"))
+        );
+        assert_eq!(
+            parse("11-22,95-1x5"),
+            Err(ParseError::new(2, 1, 10, "1x5", "invalid right"))
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        common::examples::assert_example::<Day2>(
+            include_str!("../examples/example.txt"),
+            include_str!("../examples/example.expected"),
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let ranges = [
+            (10000000001000000000, 10000000001000000000),
+            (10000000011000000001, 10000000011000000001),
+            (18446744073709551000, u64::MAX),
+        ];
+        assert_eq!(part1(&ranges), 20000000012000000001);
+        assert_eq!(part2(&ranges), 20000000012000000001);
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(part1(&[(0, 22)]), 33);
+        assert_eq!(part2(&[(0, 22)]), 33);
+        assert_eq!(part1(&[(0, 0)]), 0);
+        assert_eq!(part2(&[(0, 0)]), 0);
+    }
+}
@@ -0,0 +1,29 @@
+use common::Reference;
+
+use crate::{Day2, parse};
+
+impl Reference for Day2 {
+    fn naive1(input: &str, _: &()) -> u128 {
+        sum_ids(input, |s| {
+            let (left, right) = s.split_at(s.len() / 2);
+            s.len().is_multiple_of(2) && left == right
+        })
+    }
+
+    fn naive2(input: &str, _: &()) -> u128 {
+        sum_ids(input, |s| {
+            (1..s.len())
+                .any(|len| s.len().is_multiple_of(len) && s[..len].repeat(s.len() / len) == s)
+        })
+    }
+}
+
+fn sum_ids(input: &str, invalid: impl Fn(&str) -> bool) -> u128 {
+    parse(input)
+        .unwrap()
+        .into_iter()
+        .flat_map(|(left, right)| left..=right)
+        .filter(|id| invalid(&id.to_string()))
+        .map(u128::from)
+        .sum()
+}
@@ -0,0 +1,19 @@
+use common::{Generator, Rng};
+
+use crate::{Day3, Params};
+
+/// `size` banks, each with up to `size / 2` more batteries than the part 2
+/// count.
+impl Generator for Day3 {
+    fn generate(rng: &mut Rng, size: usize, params: &Params) -> String {
+        let mut res = String::new();
+        for _ in 0..size.max(1) {
+            let len = params.count.max(2) as u64 + rng.range(0..=size as u64 / 2);
+            for _ in 0..len {
+                res.push_str(&rng.range(1..=9).to_string());
+            }
+            res.push('\n');
+        }
+        res
+    }
+}
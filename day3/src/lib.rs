@@ -0,0 +1,223 @@
+use common::{ParseError, Solution, Wide, params};
+
+mod generate;
+mod naive;
+
+pub struct Day3;
+
+/// How many batteries part 2 turns on in each bank.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Params {
+    pub count: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { count: 12 }
+    }
+}
+
+impl common::Params for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "count" => self.count = params::value(name, value)?,
+            _ => return Err(params::unknown(name)),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<Vec<u32>>;
+    type Answer1 = u64;
+    type Answer2 = Wide;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(banks: &Vec<Vec<u32>>, _: &Params) -> u64 {
+        part1(banks)
+    }
+
+    fn part2(banks: &Vec<Vec<u32>>, params: &Params) -> Wide {
+        part2(banks, params.count)
+    }
+
+    fn check(banks: &Vec<Vec<u32>>, params: &Params, part: u8) -> Result<(), String> {
+        if part == 1 {
+            return Ok(());
+        }
+        match banks.iter().position(|bank| bank.len() < params.count) {
+            Some(i) => Err(format!(
+                "count {} is more than the {} batteries of bank {}",
+                params.count,
+                banks[i].len(),
+                i + 1
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Sum over the banks of the largest number made of `count` of their digits
+/// in order, which has as many digits as `count` asks for.
+pub fn part2(banks: &[Vec<u32>], count: usize) -> Wide {
+    let mut sum = Wide::ZERO;
+    for bank in banks {
+        let mut max_vs = vec![0; count];
+        let mut last_idx: i64 = -1;
+
+        for vi in 0..count {
+            for i in (last_idx + 1) as usize..bank.len() - (count - vi) + 1 {
+                let vmax = max_vs.get(vi).unwrap();
+                let v = bank.get(i).unwrap();
+                if v > vmax {
+                    max_vs[vi] = *v;
+                    last_idx = i as i64;
+                }
+
+                if v == &9 {
+                    break;
+                }
+            }
+        }
+        let mut bank_v = Wide::ZERO;
+        for v in max_vs {
+            bank_v = bank_v * Wide::from(10_u32) + Wide::from(v);
+        }
+        sum += bank_v;
+    }
+    sum
+}
+
+/// Sum over the banks of the largest number made of two of their digits in
+/// order.
+pub fn part1(banks: &[Vec<u32>]) -> u64 {
+    let mut sum = 0;
+
+    for bank in banks {
+        let mut max_left = 0;
+        let mut max_left_idx = 0;
+        for i in 0..bank.len() - 1 {
+            let v = bank.get(i).unwrap();
+            if v > &max_left {
+                max_left = *v;
+                max_left_idx = i;
+            }
+            if max_left == 9 {
+                break;
+            }
+        }
+
+        let mut max_right = 0;
+        for i in max_left_idx + 1..bank.len() {
+            let v = bank.get(i).unwrap();
+            if v > &max_right {
+                max_right = *v;
+            }
+            if max_right == 9 {
+                break;
+            }
+        }
+
+        sum += (max_left * 10 + max_right) as u64;
+    }
+
+    sum
+}
+
+/// Banks of battery joltages, one digit each, with at least two batteries in
+/// every bank.
+pub fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    let mut res = Vec::new();
+
+    for line in common::lines(Day3::DAY, input) {
+        let mut nums = Vec::new();
+        for (i, char) in line.text.char_indices() {
+            let token = &line.text[i..i + char.len_utf8()];
+            nums.push(
+                char.to_digit(10)
+                    .ok_or_else(|| line.error(token, "invalid number"))?,
+            );
+        }
+        if nums.len() < 2 {
+            let message = format!("expected at least 2 batteries, got {}", nums.len());
+            return Err(line.error(line.text, message));
+        }
+        res.push(nums);
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("123\n321"), Ok(vec![vec![1, 2, 3], vec![3, 2, 1]]))
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("123\n3a1"),
+            Err(ParseError::new(3, 2, 2, "a", "invalid number"))
+        );
+        assert_eq!(
+            parse("98765\n\n"),
+            Err(ParseError::new(
+                3,
+                2,
+                1,
+                "",
+                "expected at least 2 batteries, got 0"
+            ))
+        );
+        assert_eq!(
+            parse("98765\n7"),
+            Err(ParseError::new(
+                3,
+                2,
+                1,
+                "7",
+                "expected at least 2 batteries, got 1"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let banks = parse("987654321111111\n123").unwrap();
+        let mut params = Params::default();
+        assert_eq!(Day3::check(&banks, &params, 1), Ok(()));
+        assert_eq!(
+            Day3::check(&banks, &params, 2),
+            Err("count 12 is more than the 3 batteries of bank 2".to_owned())
+        );
+        params.count = 3;
+        assert_eq!(Day3::check(&banks, &params, 2), Ok(()));
+    }
+
+    #[test]
+    fn test_example() {
+        common::examples::assert_example::<Day3>(
+            include_str!("../examples/example.txt"),
+            include_str!("../examples/example.expected"),
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            part2(&parse(&"9".repeat(50)).unwrap(), 45).to_string(),
+            "9".repeat(45)
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use common::{Reference, Wide};
+
+use crate::{Day3, Params, parse};
+
+impl Reference for Day3 {
+    fn naive1(input: &str, _: &Params) -> u64 {
+        let sum: Wide = parse(input).unwrap().iter().map(|bank| best(bank, 2)).sum();
+        u64::try_from(sum).expect("two digits per bank fit")
+    }
+
+    fn naive2(input: &str, params: &Params) -> Wide {
+        parse(input)
+            .unwrap()
+            .iter()
+            .map(|bank| best(bank, params.count))
+            .sum()
+    }
+
+    fn is_valid(input: &str, params: &Params) -> bool {
+        input.lines().all(|bank| bank.len() >= params.count.max(2))
+    }
+}
+
+/// Largest number made of `count` digits of `bank` in order, trying every
+/// choice.
+fn best(bank: &[u32], count: usize) -> Wide {
+    if count == 0 {
+        return Wide::ZERO;
+    }
+    if bank.len() < count {
+        return Wide::ZERO;
+    }
+    let (first, rest) = bank.split_first().unwrap();
+    let take =
+        Wide::from(*first) * Wide::from(10_u32).pow(count as u32 - 1) + best(rest, count - 1);
+    if rest.len() >= count {
+        take.max(best(rest, count))
+    } else {
+        take
+    }
+}
@@ -0,0 +1,19 @@
+use common::{Generator, Rng};
+
+use crate::{Day4, Params};
+
+/// A `size` by `size` grid with a random density of rolls.
+impl Generator for Day4 {
+    fn generate(rng: &mut Rng, size: usize, _: &Params) -> String {
+        let side = size.max(1);
+        let density = rng.range(3..=9) as f64 / 10.0;
+        let mut res = String::new();
+        for _ in 0..side {
+            for _ in 0..side {
+                res.push(if rng.chance(density) { '@' } else { '.' });
+            }
+            res.push('\n');
+        }
+        res
+    }
+}
@@ -0,0 +1,146 @@
+use common::{ParseError, Solution, params};
+use grid::{Grid, Position};
+
+mod generate;
+mod naive;
+
+pub struct Day4;
+
+/// A roll of paper is accessible when fewer than `threshold` of its neighbours
+/// are paper.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Params {
+    pub threshold: u64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { threshold: 4 }
+    }
+}
+
+impl common::Params for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "threshold" => self.threshold = params::value(name, value)?,
+            _ => return Err(params::unknown(name)),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Map;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Map, params: &Params) -> u64 {
+        part1(map, params.threshold)
+    }
+
+    fn part2(map: &Map, params: &Params) -> u64 {
+        part2(&mut map.clone(), params.threshold)
+    }
+}
+
+/// Number of rolls removed by repeatedly removing every accessible roll.
+pub fn part2(map: &mut Map, threshold: u64) -> u64 {
+    let mut removed = 0;
+    let mut to_remove = Vec::new();
+
+    loop {
+        common::cancel::checkpoint();
+        for pos in map.positions() {
+            if map[pos] != '@' {
+                continue;
+            }
+
+            let adjacent_count = count_adjacent_paper(map, pos);
+
+            if adjacent_count < threshold {
+                to_remove.push(pos)
+            }
+        }
+
+        if to_remove.is_empty() {
+            break;
+        }
+
+        while let Some(pos) = to_remove.pop() {
+            map[pos] = 'x';
+            removed += 1;
+        }
+    }
+    removed
+}
+
+/// Number of rolls with fewer than `threshold` rolls around them.
+pub fn part1(map: &Map, threshold: u64) -> u64 {
+    let mut accessible = 0;
+
+    for pos in map.positions() {
+        if map[pos] != '@' {
+            continue;
+        }
+
+        let adjacent_count = count_adjacent_paper(map, pos);
+        if adjacent_count < threshold {
+            accessible += 1;
+        }
+    }
+
+    accessible
+}
+
+/// Number of rolls among the eight neighbours of a cell.
+pub fn count_adjacent_paper(map: &Map, pos: Position) -> u64 {
+    map.neighbours8(pos).filter(|&pos| map[pos] == '@').count() as u64
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Map::parse(Day4::DAY, input, |c| matches!(c, '@' | '.').then_some(c))
+}
+
+/// The floor, with `@` for rolls of paper, `.` for empty cells and `x` for
+/// removed rolls.
+pub type Map = Grid<char>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("..@\n.@."),
+            Ok(Map::from_rows(vec![vec!['.', '.', '@'], vec!['.', '@', '.']]).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("..@\n.#."),
+            Err(ParseError::new(4, 2, 2, "#", "invalid cell"))
+        );
+        assert_eq!(
+            parse("..@\n.@"),
+            Err(ParseError::new(4, 2, 1, ".@", "expected 3 cells, got 2"))
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        common::examples::assert_example::<Day4>(
+            include_str!("../examples/example.txt"),
+            include_str!("../examples/example.expected"),
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use common::Reference;
+
+use crate::{Day4, Params};
+
+impl Reference for Day4 {
+    fn naive1(input: &str, params: &Params) -> u64 {
+        let grid = grid(input);
+        accessible(&grid, params.threshold).len() as u64
+    }
+
+    fn naive2(input: &str, params: &Params) -> u64 {
+        let mut grid = grid(input);
+        let mut removed = 0;
+        // Remove one roll at a time until none is accessible.
+        while let Some(&(row, col)) = accessible(&grid, params.threshold).first() {
+            grid[row][col] = '.';
+            removed += 1;
+        }
+        removed
+    }
+}
+
+fn grid(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+fn accessible(grid: &[Vec<char>], threshold: u64) -> Vec<(usize, usize)> {
+    let mut res = Vec::new();
+    for row in 0..grid.len() {
+        for col in 0..grid[row].len() {
+            if grid[row][col] != '@' {
+                continue;
+            }
+            let mut neighbours = 0;
+            for dr in -1..=1_isize {
+                for dc in -1..=1_isize {
+                    let (r, c) = (row as isize + dr, col as isize + dc);
+                    if (dr, dc) != (0, 0)
+                        && r >= 0
+                        && c >= 0
+                        && grid.get(r as usize).and_then(|line| line.get(c as usize)) == Some(&'@')
+                    {
+                        neighbours += 1;
+                    }
+                }
+            }
+            if neighbours < threshold {
+                res.push((row, col));
+            }
+        }
+    }
+    res
+}
@@ -0,0 +1,22 @@
+use common::{Generator, Rng};
+
+use crate::Day5;
+
+/// `size` fresh ranges and up to `size` available IDs, spread over values
+/// that grow with `size` so the ranges keep overlapping now and then.
+impl Generator for Day5 {
+    fn generate(rng: &mut Rng, size: usize, _: &()) -> String {
+        let max = (10 * size as u64).max(60);
+        let width = (3 * size as u64).max(20);
+        let mut res = String::new();
+        for _ in 0..size.max(1) {
+            let min = rng.range(0..=max);
+            res.push_str(&format!("{}-{}\n", min, min + rng.range(0..=width)));
+        }
+        res.push('\n');
+        for _ in 0..rng.range(0..=size as u64) {
+            res.push_str(&format!("{}\n", rng.range(0..=max + width + 10)));
+        }
+        res
+    }
+}
@@ -0,0 +1,153 @@
+use common::{ParseError, Solution};
+use interval::IntervalSet;
+
+mod generate;
+mod naive;
+
+pub struct Day5;
+
+/// Fresh ingredient ID ranges and the available ingredient IDs.
+pub type Database = (Vec<(u64, u64)>, Vec<u64>);
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Database;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Database, ParseError> {
+        parse(input)
+    }
+
+    fn part1((ranges, ids): &Database, _: &()) -> u64 {
+        part1(ranges, ids)
+    }
+
+    fn part2((ranges, ids): &Database, _: &()) -> u64 {
+        part2(ranges, ids)
+    }
+}
+
+/// Number of IDs covered by the fresh ranges.
+pub fn part2(ranges: &[(u64, u64)], _: &[u64]) -> u64 {
+    simplify_ranges(ranges).len()
+}
+
+/// Number of available IDs inside a fresh range.
+pub fn part1(ranges: &[(u64, u64)], ids: &[u64]) -> u64 {
+    let fresh = simplify_ranges(ranges);
+    ids.iter().filter(|&&id| fresh.contains(id)).count() as u64
+}
+
+/// The fresh IDs, with the overlapping ranges merged.
+pub fn simplify_ranges(ranges: &[(u64, u64)]) -> IntervalSet {
+    ranges.iter().copied().collect()
+}
+
+pub fn parse(input: &str) -> Result<Database, ParseError> {
+    let mut ranges = Vec::new();
+    let mut lines = common::lines(Day5::DAY, input);
+    let mut separated = false;
+
+    for line in lines.by_ref() {
+        if line.text.is_empty() {
+            separated = true;
+            break;
+        }
+
+        let (min_str, max_str) = line
+            .text
+            .split_once('-')
+            .ok_or_else(|| line.error(line.text, "invalid range"))?;
+        ranges.push((
+            line.parse::<u64>(min_str, "number")?,
+            line.parse::<u64>(max_str, "number")?,
+        ));
+    }
+
+    if !separated {
+        let number = input.lines().count() + 1;
+        return Err(ParseError::new(
+            Day5::DAY,
+            number,
+            1,
+            "",
+            "expected blank line before ids",
+        ));
+    }
+
+    let mut ids = Vec::new();
+
+    for line in lines {
+        ids.push(line.parse::<u64>(line.text, "id")?);
+    }
+
+    Ok((ranges, ids))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(INPUT),
+            Ok((
+                vec![(3, 5), (10, 14), (16, 20), (12, 18)],
+                vec![1, 5, 8, 11, 17, 32]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("3-5\n10+14\n\n1"),
+            Err(ParseError::new(5, 2, 1, "10+14", "invalid range"))
+        );
+        assert_eq!(
+            parse("3-5\n\n1\n5x"),
+            Err(ParseError::new(5, 4, 1, "5x", "invalid id"))
+        );
+        assert_eq!(
+            parse("3-5\n10-14"),
+            Err(ParseError::new(
+                5,
+                3,
+                1,
+                "",
+                "expected blank line before ids"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_simpify_ranges() {
+        assert_eq!(
+            simplify_ranges(&[(3, 5), (10, 14), (16, 20), (12, 18)])
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![(3, 5), (10, 20)],
+        );
+
+        assert_eq!(
+            simplify_ranges(&[(1, 10), (10, 14), (16, 20), (12, 18)])
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![(1, 20)],
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        common::examples::assert_example::<Day5>(
+            include_str!("../examples/example.txt"),
+            include_str!("../examples/example.expected"),
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use common::Reference;
+
+use crate::{Day5, parse};
+
+impl Reference for Day5 {
+    fn naive1(input: &str, _: &()) -> u64 {
+        let (ranges, ids) = parse(input).unwrap();
+        ids.iter()
+            .filter(|&&id| ranges.iter().any(|&(min, max)| min <= id && id <= max))
+            .count() as u64
+    }
+
+    fn naive2(input: &str, _: &()) -> u64 {
+        let (ranges, _) = parse(input).unwrap();
+        let Some(max) = ranges.iter().map(|&(_, max)| max).max() else {
+            return 0;
+        };
+        (0..=max)
+            .filter(|&id| ranges.iter().any(|&(min, max)| min <= id && id <= max))
+            .count() as u64
+    }
+
+    fn is_valid(input: &str, _: &()) -> bool {
+        let (ranges, _) = parse(input).unwrap();
+        ranges.iter().all(|&(min, max)| min <= max)
+    }
+}
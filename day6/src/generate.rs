@@ -0,0 +1,44 @@
+use common::{Generator, Rng};
+
+use crate::Day6;
+
+/// A worksheet of `size` problems with up to four numbers each.
+impl Generator for Day6 {
+    fn generate(rng: &mut Rng, size: usize, _: &()) -> String {
+        let rows = rng.range(1..=4) as usize;
+        let mut lines = vec![String::new(); rows + 1];
+
+        for problem in 0..size.max(1) {
+            if problem > 0 {
+                for line in &mut lines {
+                    line.push(' ');
+                }
+            }
+            let width = rng.range(1..=3) as usize;
+            let left = rng.chance(0.5);
+            // Sorted lengths keep the digits of each column together.
+            let mut lengths: Vec<usize> = (0..rows)
+                .map(|_| rng.range(1..=width as u64) as usize)
+                .collect();
+            lengths[0] = width;
+            lengths.sort();
+            if rng.chance(0.5) {
+                lengths.reverse();
+            }
+            for (line, digits) in lines[..rows].iter_mut().zip(lengths) {
+                let num: String = (0..digits)
+                    .map(|_| char::from_digit(rng.range(1..=9) as u32, 10).unwrap())
+                    .collect();
+                if left {
+                    line.push_str(&format!("{:<width$}", num));
+                } else {
+                    line.push_str(&format!("{:>width$}", num));
+                }
+            }
+            let op = rng.pick(&['*', '+']);
+            lines[rows].push_str(&format!("{:<width$}", op));
+        }
+
+        lines.iter().map(|line| format!("{}\n", line)).collect()
+    }
+}
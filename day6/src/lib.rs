@@ -0,0 +1,219 @@
+use common::{Line, ParseError, Solution, Wide};
+
+mod generate;
+mod naive;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = (Vec<Column>, Vec<Column>);
+    type Answer1 = Wide;
+    type Answer2 = Wide;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(Vec<Column>, Vec<Column>), ParseError> {
+        Ok((parse1(input)?, parse2(input)?))
+    }
+
+    fn part1((cols, _): &(Vec<Column>, Vec<Column>), _: &()) -> Wide {
+        calc(cols)
+    }
+
+    fn part2((_, cols): &(Vec<Column>, Vec<Column>), _: &()) -> Wide {
+        calc(cols)
+    }
+}
+
+/// Problems read right to left in columns, one number per column of digits.
+pub fn parse2(input: &str) -> Result<Vec<Column>, ParseError> {
+    let lines: Vec<Line> = common::lines(Day6::DAY, input).collect();
+    let (op_line, num_lines) = lines.split_last().ok_or_else(empty_input)?;
+    let cols = op_line.text.split_whitespace().count();
+    let mut nums: Vec<Vec<u64>> = vec![Vec::new(); cols];
+    let rows: Vec<Vec<char>> = num_lines
+        .iter()
+        .map(|line| line.text.chars().collect())
+        .collect();
+    let line_len = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let mut idx = 0;
+
+    for i in (0..line_len).rev() {
+        let num_str = rows
+            .iter()
+            .map(|row| row.get(i).copied().unwrap_or(' '))
+            .collect::<String>()
+            .trim()
+            .to_string();
+
+        if num_str.is_empty() {
+            idx += 1;
+            continue;
+        }
+
+        let num = num_str.parse::<u64>().map_err(|_| {
+            // Numbers are read top to bottom, so point at the first digit.
+            let row = rows
+                .iter()
+                .position(|row| row.get(i).is_some_and(|c| !c.is_whitespace()))
+                .unwrap_or(0);
+            ParseError::new(
+                Day6::DAY,
+                num_lines[row].number,
+                i + 1,
+                &num_str,
+                "invalid number",
+            )
+        })?;
+
+        nums.get_mut(idx)
+            .ok_or_else(|| op_line.error("", "more columns than operations"))?
+            .push(num);
+    }
+
+    let mut res = Vec::new();
+
+    for (i, op_str) in op_line.text.split_whitespace().rev().enumerate() {
+        res.push(Column::new(nums[i].clone(), parse_op(op_line, op_str)?));
+    }
+
+    Ok(res)
+}
+
+/// Sum of the results of the problems, which can be far beyond `u64`.
+pub fn calc(cols: &[Column]) -> Wide {
+    let mut total = Wide::ZERO;
+
+    for col in cols {
+        total += match col.op {
+            Operation::Mul => col.nums.iter().copied().product(),
+            Operation::Add => col.nums.iter().copied().sum(),
+        };
+    }
+
+    total
+}
+
+/// Problems read top to bottom, one number per row.
+pub fn parse1(input: &str) -> Result<Vec<Column>, ParseError> {
+    let lines: Vec<Line> = common::lines(Day6::DAY, input).collect();
+    let (op_line, num_lines) = lines.split_last().ok_or_else(empty_input)?;
+    let cols = op_line.text.split_whitespace().count();
+
+    let mut nums: Vec<Vec<u64>> = vec![Vec::new(); cols];
+    let mut res = Vec::new();
+
+    for line in num_lines {
+        for (i, num_str) in line.text.split_whitespace().enumerate() {
+            let num = line.parse::<u64>(num_str, "number")?;
+            nums.get_mut(i)
+                .ok_or_else(|| line.error(num_str, "more numbers than operations"))?
+                .push(num);
+        }
+    }
+
+    for (i, op_str) in op_line.text.split_whitespace().enumerate() {
+        res.push(Column::new(nums[i].clone(), parse_op(op_line, op_str)?));
+    }
+
+    Ok(res)
+}
+
+fn parse_op(line: &Line, op_str: &str) -> Result<Operation, ParseError> {
+    match op_str {
+        "*" => Ok(Operation::Mul),
+        "+" => Ok(Operation::Add),
+        _ => Err(line.error(op_str, "invalid operation")),
+    }
+}
+
+fn empty_input() -> ParseError {
+    ParseError::new(Day6::DAY, 1, 1, "", "expected numbers and operations")
+}
+
+/// One problem: its numbers and the operation combining them.
+#[derive(Debug, PartialEq)]
+pub struct Column {
+    pub nums: Vec<u64>,
+    pub op: Operation,
+}
+
+impl Column {
+    pub fn new(nums: Vec<u64>, op: Operation) -> Self {
+        Self { nums, op }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Operation {
+    Mul,
+    Add,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn test_parse1() {
+        assert_eq!(
+            parse1(INPUT),
+            Ok(vec![
+                Column::new(vec![123, 45, 6], Operation::Mul),
+                Column::new(vec![328, 64, 98], Operation::Add),
+                Column::new(vec![51, 387, 215], Operation::Mul),
+                Column::new(vec![64, 23, 314], Operation::Add),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        common::examples::assert_example::<Day6>(
+            include_str!("../examples/example.txt"),
+            include_str!("../examples/example.expected"),
+        );
+    }
+
+    #[test]
+    fn test_parse2() {
+        assert_eq!(
+            parse2(INPUT),
+            Ok(vec![
+                Column::new(vec![4, 431, 623], Operation::Add),
+                Column::new(vec![175, 581, 32], Operation::Mul),
+                Column::new(vec![8, 248, 369], Operation::Add),
+                Column::new(vec![356, 24, 1], Operation::Mul),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse1("1 2\n3 x\n* +"),
+            Err(ParseError::new(6, 2, 3, "x", "invalid number"))
+        );
+        assert_eq!(
+            parse1("1 2\n3 4\n* -"),
+            Err(ParseError::new(6, 3, 3, "-", "invalid operation"))
+        );
+        assert_eq!(
+            parse2("1 2\n3 x\n* +"),
+            Err(ParseError::new(6, 1, 3, "2x", "invalid number"))
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = "10000000000 10\n10000000000 20\n*           +";
+        assert_eq!(
+            calc(&parse1(input).unwrap()).to_string(),
+            "100000000000000000030"
+        );
+    }
+}
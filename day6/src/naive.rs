@@ -0,0 +1,75 @@
+use common::{Reference, Wide};
+
+use crate::Day6;
+
+impl Reference for Day6 {
+    fn naive1(input: &str, _: &()) -> Wide {
+        let rows: Vec<Vec<&str>> = input
+            .lines()
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        let (ops, nums) = rows.split_last().unwrap();
+        ops.iter()
+            .enumerate()
+            .map(|(i, op)| apply(op, nums.iter().map(|row| row[i].parse().unwrap())))
+            .sum()
+    }
+
+    fn naive2(input: &str, _: &()) -> Wide {
+        let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+        let width = rows.iter().map(Vec::len).max().unwrap();
+        let cell = |row: &Vec<char>, col: usize| row.get(col).copied().unwrap_or(' ');
+        let (ops, nums) = rows.split_last().unwrap();
+
+        // Problems are runs of columns separated by blank columns, with the
+        // operation under their leftmost column and one number per column.
+        let mut total = Wide::ZERO;
+        let mut start = 0;
+        while start < width {
+            let mut end = start;
+            while end < width && nums.iter().any(|row| cell(row, end) != ' ') {
+                end += 1;
+            }
+            if end > start {
+                let op = cell(ops, start).to_string();
+                let numbers = (start..end).map(|col| {
+                    nums.iter()
+                        .map(|row| cell(row, col))
+                        .filter(|c| c.is_ascii_digit())
+                        .collect::<String>()
+                        .parse()
+                        .unwrap()
+                });
+                total += apply(&op, numbers);
+            }
+            start = end + 1;
+        }
+        total
+    }
+
+    fn is_valid(input: &str, _: &()) -> bool {
+        let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let Some((ops, nums)) = rows.split_last() else {
+            return false;
+        };
+        if nums.is_empty() || rows.iter().any(|row| row.len() != ops.len()) {
+            return false;
+        }
+        // Each problem starts after a blank column, with its operation there,
+        // and the digits of each column are next to each other.
+        (0..ops.len()).all(|col| {
+            let blank = |col: usize| nums.iter().all(|row| row[col] == b' ');
+            let start = !blank(col) && (col == 0 || blank(col - 1));
+            let digits: String = nums.iter().map(|row| row[col] as char).collect();
+            (ops[col] != b' ') == start && !digits.trim().contains(' ')
+        })
+    }
+}
+
+fn apply(op: &str, nums: impl Iterator<Item = u64>) -> Wide {
+    match op {
+        "*" => nums.product(),
+        "+" => nums.sum(),
+        _ => panic!("invalid operation {:?}", op),
+    }
+}
@@ -0,0 +1,26 @@
+use common::{Generator, Rng};
+
+use crate::Day7;
+
+/// A manifold `size` columns wide and one row taller, with splitters on every
+/// other row and never two side by side.
+impl Generator for Day7 {
+    fn generate(rng: &mut Rng, size: usize, _: &()) -> String {
+        let cols = size.max(1);
+        let mut map = vec![vec!['.'; cols]; cols + 1];
+        map[0][rng.index(cols)] = 'S';
+        for row in map.iter_mut().skip(2).step_by(2) {
+            let mut col = 0;
+            while col < cols {
+                if rng.chance(0.4) {
+                    row[col] = '^';
+                    col += 1;
+                }
+                col += 1;
+            }
+        }
+        map.iter()
+            .map(|row| format!("{}\n", row.iter().collect::<String>()))
+            .collect()
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{Line, ParseError, Solution, Wide};
+use grid::Grid;
+
+mod generate;
+mod naive;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Grid<char>;
+    type Answer1 = u64;
+    type Answer2 = Wide;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Grid<char>, _: &()) -> u64 {
+        part1(map)
+    }
+
+    fn part2(map: &Grid<char>, _: &()) -> Wide {
+        part2(map)
+    }
+}
+
+/// Number of timelines a single particle ends up in, or 0 without a start.
+pub fn part2(map: &Grid<char>) -> Wide {
+    let Some(start) = start(map) else {
+        return Wide::ZERO;
+    };
+    let mut beam: HashMap<usize, Wide> = HashMap::new();
+    beam.insert(start, Wide::ONE);
+    let cols = map.cols();
+
+    for line in map.iter_rows().skip(1) {
+        let positions: Vec<(usize, Wide)> = beam.drain().collect();
+
+        for (i, n) in positions {
+            if line[i] == '^' {
+                if i > 0 {
+                    *beam.entry(i - 1).or_default() += n.clone();
+                }
+
+                if i < cols - 1 {
+                    *beam.entry(i + 1).or_default() += n;
+                }
+            } else {
+                *beam.entry(i).or_default() += n;
+            }
+        }
+    }
+
+    beam.into_values().sum()
+}
+
+/// Number of times the beam is split, or 0 without a start.
+pub fn part1(map: &Grid<char>) -> u64 {
+    let Some(start) = start(map) else {
+        return 0;
+    };
+    let mut beam: HashSet<usize> = HashSet::new();
+    beam.insert(start);
+    let cols = map.cols();
+    let mut splits = 0;
+
+    for line in map.iter_rows().skip(1) {
+        let positions: Vec<usize> = beam.iter().copied().collect();
+
+        for i in positions {
+            if line[i] == '^' {
+                splits += 1;
+                beam.remove(&i);
+                if i > 0 {
+                    beam.insert(i - 1);
+                }
+
+                if i < cols - 1 {
+                    beam.insert(i + 1);
+                }
+            }
+        }
+    }
+
+    splits
+}
+
+/// Column of the start `S` on the first row.
+pub fn start(map: &Grid<char>) -> Option<usize> {
+    map.iter_rows().next()?.iter().position(|&c| c == 'S')
+}
+
+/// The manifold, which must have a start `S` on its first row.
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    let map = Grid::parse(Day7::DAY, input, |c| {
+        matches!(c, '.' | '^' | 'S').then_some(c)
+    })?;
+    if start(&map).is_none() {
+        let text = input.lines().next().unwrap_or(input);
+        let line = Line {
+            day: Day7::DAY,
+            number: 1,
+            text,
+        };
+        return Err(line.error(text, "expected start S on the first line"));
+    }
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{Generator, Rng};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(".S.\n.^."),
+            Ok(Grid::from_rows(vec![vec!['.', 'S', '.'], vec!['.', '^', '.']]).unwrap()),
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse(".S.\n.v."),
+            Err(ParseError::new(7, 2, 2, "v", "invalid cell"))
+        );
+        assert_eq!(
+            parse(".S.\n.^"),
+            Err(ParseError::new(7, 2, 1, ".^", "expected 3 cells, got 2"))
+        );
+        assert_eq!(
+            parse("...\n.S."),
+            Err(ParseError::new(
+                7,
+                1,
+                1,
+                "...",
+                "expected start S on the first line"
+            ))
+        );
+        assert_eq!(
+            parse(""),
+            Err(ParseError::new(
+                7,
+                1,
+                1,
+                "",
+                "expected start S on the first line"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        // The generated manifold of `aoc gen 7 --size 3000 --seed 3`, whose
+        // timelines wrapped around in a u64.
+        let input = Day7::generate(&mut Rng::new(3), 3000, &());
+        assert_eq!(
+            part2(&parse(&input).unwrap()).to_string(),
+            "34938093250417924423523339217468626522525872555279115524437044765634220722149867277067477348766460715631591810501382175558805873600359672530953809971127695131533"
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        common::examples::assert_example::<Day7>(
+            include_str!("../examples/example.txt"),
+            include_str!("../examples/example.expected"),
+        );
+    }
+}
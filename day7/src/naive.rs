@@ -0,0 +1,72 @@
+use std::collections::HashSet;
+
+use common::{Reference, Wide};
+use grid::{Grid, Position};
+
+use crate::{Day7, parse};
+
+impl Reference for Day7 {
+    fn naive1(input: &str, _: &()) -> u64 {
+        let map = parse(input).unwrap();
+        let start = map.row(0).iter().position(|&c| c == 'S').unwrap();
+        // Follow every beam down, remembering the splitters it reached.
+        let mut hit = HashSet::new();
+        let mut seen = HashSet::new();
+        let mut stack = vec![(0, start)];
+        while let Some((row, col)) = stack.pop() {
+            if !seen.insert((row, col)) || row + 1 >= map.rows() {
+                continue;
+            }
+            if map[Position::new(row + 1, col)] == '^' {
+                hit.insert((row + 1, col));
+                stack.extend(sides(&map, row + 1, col));
+            } else {
+                stack.push((row + 1, col));
+            }
+        }
+        hit.len() as u64
+    }
+
+    fn naive2(input: &str, _: &()) -> Wide {
+        let map = parse(input).unwrap();
+        let start = map.row(0).iter().position(|&c| c == 'S').unwrap();
+        timelines(&map, 0, start)
+    }
+
+    fn is_valid(input: &str, _: &()) -> bool {
+        let map = parse(input).unwrap();
+        map.rows() >= 2
+            && map.row(0).contains(&'S')
+            && map
+                .iter_rows()
+                .all(|row| !row.windows(2).any(|w| w == ['^', '^']))
+    }
+}
+
+/// Where a beam split at `(row, col)` continues, dropping the sides outside
+/// the map.
+fn sides(map: &Grid<char>, row: usize, col: usize) -> Vec<(usize, usize)> {
+    let mut res = Vec::new();
+    if col > 0 {
+        res.push((row, col - 1));
+    }
+    if col + 1 < map.cols() {
+        res.push((row, col + 1));
+    }
+    res
+}
+
+/// Number of paths from the beam at `(row, col)` to the bottom.
+fn timelines(map: &Grid<char>, row: usize, col: usize) -> Wide {
+    if row + 1 >= map.rows() {
+        return Wide::ONE;
+    }
+    if map[Position::new(row + 1, col)] == '^' {
+        sides(map, row + 1, col)
+            .into_iter()
+            .map(|(row, col)| timelines(map, row, col))
+            .sum()
+    } else {
+        timelines(map, row + 1, col)
+    }
+}
@@ -0,0 +1,16 @@
+use common::{Generator, Rng};
+
+use crate::{Day8, Params};
+
+/// `size` junction boxes, at least two, in a cube that grows with `size`.
+impl Generator for Day8 {
+    fn generate(rng: &mut Rng, size: usize, _: &Params) -> String {
+        let side = (10 * size as u64).max(40);
+        let mut res = String::new();
+        for _ in 0..size.max(2) {
+            let mut coord = || rng.range(0..=side);
+            res.push_str(&format!("{},{},{}\n", coord(), coord(), coord()));
+        }
+        res
+    }
+}
@@ -0,0 +1,214 @@
+use common::{ParseError, Solution, params};
+use unionfind::UnionFind;
+
+mod generate;
+mod naive;
+
+pub struct Day8;
+
+/// How many of the shortest connections part 1 makes, and how many of the
+/// largest circuits it multiplies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Params {
+    pub connections: usize,
+    pub count: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            connections: 1000,
+            count: 3,
+        }
+    }
+}
+
+impl common::Params for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "connections" => self.connections = params::value(name, value)?,
+            "count" => self.count = params::value(name, value)?,
+            _ => return Err(params::unknown(name)),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<Point>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(points: &Vec<Point>, params: &Params) -> u64 {
+        part1(points, params.connections, params.count)
+    }
+
+    fn part2(points: &Vec<Point>, _: &Params) -> u64 {
+        part2(points)
+    }
+}
+
+/// Product of the X coordinates of the last two junction boxes connected to
+/// join all of them into one circuit, or 0 if there are fewer than two boxes
+/// to connect.
+pub fn part2(points: &[Point]) -> u64 {
+    let mut circuits = UnionFind::new(points.len());
+
+    for (n, seg) in segments(points).into_iter().enumerate() {
+        if n % 4096 == 0 {
+            common::cancel::checkpoint();
+        }
+        circuits.union(seg.a, seg.b);
+        if circuits.count() == 1 {
+            return (points[seg.a].x * points[seg.b].x) as u64;
+        }
+    }
+
+    // Any two boxes get connected in the end, so only fewer get here.
+    0
+}
+
+/// Product of the sizes of the `count` largest circuits after connecting the
+/// `connections` closest pairs.
+pub fn part1(points: &[Point], connections: usize, count: usize) -> u64 {
+    let mut circuits = UnionFind::new(points.len());
+
+    for seg in segments(points).iter().take(connections) {
+        circuits.union(seg.a, seg.b);
+    }
+
+    let mut lengths = circuits.sizes();
+    lengths.sort();
+
+    lengths
+        .iter()
+        .rev()
+        .take(count)
+        .map(|&len| len as u64)
+        .product()
+}
+
+/// Every pair of junction boxes, closest first.
+pub fn segments(points: &[Point]) -> Vec<Segment> {
+    let mut segments = Vec::new();
+
+    for i in 0..points.len() {
+        common::cancel::checkpoint();
+        for j in i + 1..points.len() {
+            segments.push(Segment::new(points, i, j));
+        }
+    }
+
+    segments.sort_by_key(|seg| seg.d);
+    segments
+}
+
+/// Squared distance between two points.
+pub fn distance(a: &Point, b: &Point) -> i64 {
+    (a.x - b.x).pow(2) + (a.y - b.y).pow(2) + (a.z - b.z).pow(2)
+}
+
+/// Two junction boxes, by index, and their squared distance.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Segment {
+    pub a: usize,
+    pub b: usize,
+    pub d: i64,
+}
+
+impl Segment {
+    pub fn new(points: &[Point], a: usize, b: usize) -> Self {
+        Self {
+            a,
+            b,
+            d: distance(&points[a], &points[b]),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+    let mut res = Vec::new();
+
+    for line in common::lines(Day8::DAY, input) {
+        let parts = line
+            .text
+            .splitn(3, ',')
+            .map(|s| line.parse::<i64>(s, "number"))
+            .collect::<Result<Vec<_>, _>>()?;
+        if parts.len() != 3 {
+            return Err(line.error(line.text, "expected x,y,z"));
+        }
+        res.push(Point::new(parts[0], parts[1], parts[2]));
+    }
+
+    Ok(res)
+}
+
+/// Position of a junction box.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("1,2,3\n4,5,6"),
+            Ok(vec![Point::new(1, 2, 3), Point::new(4, 5, 6)])
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("1,2,3\n4,5"),
+            Err(ParseError::new(8, 2, 1, "4,5", "expected x,y,z"))
+        );
+        assert_eq!(
+            parse("1,2,3\n4,5,6,7"),
+            Err(ParseError::new(8, 2, 5, "6,7", "invalid number"))
+        );
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance(&Point::new(0, 0, 0), &Point::new(3, 4, 0)), 25);
+    }
+
+    #[test]
+    fn test_example() {
+        common::examples::assert_example::<Day8>(
+            include_str!("../examples/example.txt"),
+            include_str!("../examples/example.expected"),
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        // Every box is in some circuit after the second connection, but
+        // they only form one with the third.
+        let points = parse("0,0,0\n1,0,0\n10,0,0\n12,0,0").unwrap();
+        assert_eq!(part2(&points), 10);
+        assert_eq!(part2(&parse("5,0,0").unwrap()), 0);
+        assert_eq!(part2(&[]), 0);
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+
+use common::Reference;
+
+use crate::{Day8, Params, Point, distance, parse};
+
+impl Reference for Day8 {
+    fn naive1(input: &str, params: &Params) -> u64 {
+        let points = parse(input).unwrap();
+        let pairs = closest_pairs(&points);
+        let edges = &pairs[..params.connections.min(pairs.len())];
+
+        let mut sizes: Vec<u64> = components(points.len(), edges)
+            .iter()
+            .map(|c| c.len() as u64)
+            .collect();
+        sizes.sort();
+        sizes.iter().rev().take(params.count).product()
+    }
+
+    fn naive2(input: &str, _: &Params) -> u64 {
+        let points = parse(input).unwrap();
+        let pairs = closest_pairs(&points);
+        // Connect pairs until everything is one circuit, which fewer than two
+        // points never need.
+        for n in 1..=pairs.len() {
+            if components(points.len(), &pairs[..n]).len() == 1 {
+                let (i, j) = pairs[n - 1];
+                return (points[i].x * points[j].x) as u64;
+            }
+        }
+        0
+    }
+
+    fn is_valid(input: &str, _: &Params) -> bool {
+        let points = parse(input).unwrap();
+        let distinct: HashSet<&Point> = points.iter().collect();
+        points.len() >= 2 && distinct.len() == points.len()
+    }
+}
+
+/// All pairs of point indices, closest first, ties in input order.
+fn closest_pairs(points: &[Point]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            pairs.push((i, j));
+        }
+    }
+    pairs.sort_by_key(|&(i, j)| distance(&points[i], &points[j]));
+    pairs
+}
+
+/// Connected components of `n` points joined by `edges`.
+fn components(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    let mut seen = vec![false; n];
+    let mut res = Vec::new();
+    for start in 0..n {
+        if seen[start] {
+            continue;
+        }
+        seen[start] = true;
+        let mut component = vec![start];
+        let mut i = 0;
+        while i < component.len() {
+            let p = component[i];
+            for &(a, b) in edges {
+                let other = if a == p {
+                    b
+                } else if b == p {
+                    a
+                } else {
+                    continue;
+                };
+                if !seen[other] {
+                    seen[other] = true;
+                    component.push(other);
+                }
+            }
+            i += 1;
+        }
+        res.push(component);
+    }
+    res
+}
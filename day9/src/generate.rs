@@ -0,0 +1,75 @@
+use common::{Generator, Rng};
+
+use crate::Day9;
+
+/// A loop around `size` adjacent columns of tiles, each spanning rows that
+/// overlap the previous column's, listed from a random corner.
+impl Generator for Day9 {
+    fn generate(rng: &mut Rng, size: usize, _: &()) -> String {
+        let mut xs = vec![rng.range(0..=3)];
+        let mut spans: Vec<(u64, u64)> = Vec::new();
+        for _ in 0..size.max(1) {
+            xs.push(xs.last().unwrap() + rng.range(1..=4));
+            let span = loop {
+                let bottom = rng.range(0..=10);
+                let span = (bottom, bottom + rng.range(1..=8));
+                match spans.last() {
+                    Some(&(b, t)) if span.0.max(b) >= span.1.min(t) => continue,
+                    _ => break span,
+                }
+            };
+            spans.push(span);
+        }
+
+        // Along the tops from left to right, then back along the bottoms.
+        let mut corners = vec![(xs[0], spans[0].0)];
+        for (i, &(_, top)) in spans.iter().enumerate() {
+            corners.extend([(xs[i], top), (xs[i + 1], top)]);
+        }
+        for (i, &(bottom, _)) in spans.iter().enumerate().rev() {
+            corners.extend([(xs[i + 1], bottom), (xs[i], bottom)]);
+        }
+
+        let corners = without_straight(corners);
+        let start = rng.index(corners.len());
+        let mut res = String::new();
+        for i in 0..corners.len() {
+            let (x, y) = corners[(start + i) % corners.len()];
+            res.push_str(&format!("{},{}\n", x, y));
+        }
+        res
+    }
+}
+
+fn is_straight(p1: (u64, u64), p2: (u64, u64), p3: (u64, u64)) -> bool {
+    (p1.0 == p2.0 && p2.0 == p3.0) || (p1.1 == p2.1 && p2.1 == p3.1)
+}
+
+/// Drops repeated corners and those in the middle of a straight edge.
+fn without_straight(corners: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+    // One pass handles everything but the corners around the wrap.
+    let mut res: Vec<(u64, u64)> = Vec::with_capacity(corners.len());
+    for p in corners {
+        if res.last() == Some(&p) {
+            continue;
+        }
+        while res.len() >= 2 && is_straight(res[res.len() - 2], res[res.len() - 1], p) {
+            res.pop();
+        }
+        res.push(p);
+    }
+
+    loop {
+        let n = res.len();
+        let redundant = (0..n).find(|&i| {
+            let (p1, p2, p3) = (res[(i + n - 1) % n], res[i], res[(i + 1) % n]);
+            p1 == p2 || is_straight(p1, p2, p3)
+        });
+        match redundant {
+            Some(i) => {
+                res.remove(i);
+            }
+            None => return res,
+        }
+    }
+}
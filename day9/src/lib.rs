@@ -0,0 +1,289 @@
+use common::{ParseError, Solution};
+
+mod generate;
+mod naive;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<(u64, u64)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(points: &Vec<(u64, u64)>, _: &()) -> u64 {
+        part1(points)
+    }
+
+    fn part2(points: &Vec<(u64, u64)>, _: &()) -> u64 {
+        part2(points)
+    }
+}
+
+/// Largest rectangle with red tiles in two opposite corners that only
+/// covers red or green tiles.
+pub fn part2(points: &[(u64, u64)]) -> u64 {
+    let outside = Outside::new(points);
+    let mut max_area = 0;
+
+    for (i, &p1) in points.iter().enumerate() {
+        common::cancel::checkpoint();
+        for &p2 in &points[i + 1..] {
+            let cur_area = area(p1, p2);
+            if cur_area > max_area && !outside.touches(p1, p2) {
+                max_area = cur_area;
+            }
+        }
+    }
+
+    max_area
+}
+
+/// The tiles outside the loop, on a grid compressed to the coordinates of the
+/// points and the gaps between them.
+pub struct Outside {
+    cols: Vec<u64>,
+    rows: Vec<u64>,
+    /// Number of compressed cells with tiles outside the loop above and to
+    /// the left of each cell.
+    sums: Vec<Vec<u32>>,
+}
+
+impl Outside {
+    pub fn new(points: &[(u64, u64)]) -> Self {
+        let coords = |coord: fn(&(u64, u64)) -> u64| {
+            let mut res: Vec<u64> = points.iter().map(coord).collect();
+            res.sort_unstable();
+            res.dedup();
+            res
+        };
+        let (cols, rows) = (coords(|p| p.0), coords(|p| p.1));
+        // Coordinate `i` is cell `2 * i + 1` and the gap after it `2 * i + 2`,
+        // with an empty border of gaps all around.
+        let (width, height) = (2 * cols.len() + 1, 2 * rows.len() + 1);
+        let index = |coords: &[u64], value| 2 * coords.binary_search(&value).unwrap() + 1;
+
+        let mut on_loop = vec![vec![false; height]; width];
+        for i in 0..points.len() {
+            let (p1, p2) = (points[i], points[(i + 1) % points.len()]);
+            if p1.0 != p2.0 && p1.1 != p2.1 {
+                panic!("invalid points");
+            }
+            let (c1, c2) = (index(&cols, p1.0), index(&cols, p2.0));
+            let (r1, r2) = (index(&rows, p1.1), index(&rows, p2.1));
+            for column in &mut on_loop[c1.min(c2)..=c1.max(c2)] {
+                column[r1.min(r2)..=r1.max(r2)].fill(true);
+            }
+        }
+
+        // Flood the outside from the border.
+        let mut outside = vec![vec![false; height]; width];
+        let mut stack = vec![(0_usize, 0_usize)];
+        outside[0][0] = true;
+        while let Some((col, row)) = stack.pop() {
+            let next = [
+                (col.wrapping_sub(1), row),
+                (col + 1, row),
+                (col, row.wrapping_sub(1)),
+                (col, row + 1),
+            ];
+            for (col, row) in next {
+                if col < width && row < height && !on_loop[col][row] && !outside[col][row] {
+                    outside[col][row] = true;
+                    stack.push((col, row));
+                }
+            }
+        }
+
+        // Gaps between neighbouring coordinates have no tiles, so it doesn't
+        // matter which side of the loop they are on. No rectangle reaches the
+        // border.
+        let has_tiles = |coords: &[u64], cell: usize| {
+            let gap = cell / 2;
+            cell % 2 == 1 || (gap > 0 && gap < coords.len() && coords[gap] - coords[gap - 1] > 1)
+        };
+        let mut sums = vec![vec![0; height + 1]; width + 1];
+        for col in 0..width {
+            for row in 0..height {
+                let bad = outside[col][row] && has_tiles(&cols, col) && has_tiles(&rows, row);
+                sums[col + 1][row + 1] =
+                    sums[col][row + 1] + sums[col + 1][row] - sums[col][row] + u32::from(bad);
+            }
+        }
+
+        Self { cols, rows, sums }
+    }
+
+    /// Whether the rectangle with corners `p1` and `p2`, two of the points,
+    /// covers any tile outside the loop.
+    pub fn touches(&self, p1: (u64, u64), p2: (u64, u64)) -> bool {
+        let index = |coords: &[u64], value| 2 * coords.binary_search(&value).unwrap() + 1;
+        let (c1, c2) = (index(&self.cols, p1.0), index(&self.cols, p2.0));
+        let (r1, r2) = (index(&self.rows, p1.1), index(&self.rows, p2.1));
+        let (c1, c2) = (c1.min(c2), c1.max(c2) + 1);
+        let (r1, r2) = (r1.min(r2), r1.max(r2) + 1);
+        let s = &self.sums;
+        s[c2][r2] + s[c1][r1] != s[c1][r2] + s[c2][r1]
+    }
+}
+
+/// Largest rectangle with red tiles in two opposite corners.
+pub fn part1(points: &[(u64, u64)]) -> u64 {
+    let mut max_square = 0;
+    for i in 0..points.len() {
+        common::cancel::checkpoint();
+        for j in i + 1..points.len() {
+            let sq = area(points[i], points[j]);
+            if sq > max_square {
+                max_square = sq;
+            }
+        }
+    }
+
+    max_square
+}
+
+/// Area in tiles of the rectangle with corners `p1` and `p2`.
+pub fn area(p1: (u64, u64), p2: (u64, u64)) -> u64 {
+    (p1.0.abs_diff(p2.0) + 1) * (p1.1.abs_diff(p2.1) + 1)
+}
+
+/// The red tiles in loop order, each in line with the one before it and the
+/// last in line with the first.
+pub fn parse(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    let in_line = |p1: (u64, u64), p2: (u64, u64)| p1.0 == p2.0 || p1.1 == p2.1;
+    let mut points = Vec::new();
+    let mut last = None;
+
+    for line in common::lines(Day9::DAY, input) {
+        let (s1, s2) = line
+            .text
+            .split_once(',')
+            .ok_or_else(|| line.error(line.text, "invalid line"))?;
+        let point = (
+            line.parse::<u64>(s1, "number")?,
+            line.parse::<u64>(s2, "number")?,
+        );
+        if let Some(&prev) = points.last()
+            && !in_line(prev, point)
+        {
+            return Err(line.error(line.text, "not in line with the previous point"));
+        }
+        points.push(point);
+        last = Some(line);
+    }
+
+    if let (Some(line), Some(&first), Some(&end)) = (last, points.first(), points.last())
+        && !in_line(end, first)
+    {
+        return Err(line.error(line.text, "not in line with the first point"));
+    }
+    Ok(points)
+}
+
+/// Draws the loop as SVG, with an optional rectangle highlighted.
+pub fn to_svg(points: &[(u64, u64)], rect: Option<((u64, u64), (u64, u64))>) -> String {
+    let min_x = points.iter().map(|(x, _)| *x).min().unwrap();
+    let max_x = points.iter().map(|(x, _)| *x).max().unwrap();
+    let min_y = points.iter().map(|(_, y)| *y).min().unwrap();
+    let max_y = points.iter().map(|(_, y)| *y).max().unwrap();
+
+    let padding = 10;
+    let viewbox_x = min_x.saturating_sub(padding);
+    let viewbox_y = min_y.saturating_sub(padding);
+    let viewbox_width = max_x - min_x + 2 * padding;
+    let viewbox_height = max_y - min_y + 2 * padding;
+
+    let mut path = String::new();
+    let mut extra = String::new();
+    for (i, (x, y)) in points.iter().enumerate() {
+        if i == 0 {
+            path.push_str(&format!("M {},{}", x, y));
+        } else {
+            path.push_str(&format!(" L {},{}", x, y));
+        }
+    }
+    path.push_str(" Z");
+
+    if let Some(((x1, y1), (x2, y2))) = rect {
+        extra.push_str(&format!(
+            r#"
+  <rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="blue" stroke-width="30" />"#,
+            x1.min(x2),
+            y1.min(y2),
+            x1.abs_diff(x2) + 1,
+            y1.abs_diff(y2) + 1,
+        ));
+    }
+
+    format!(
+        r#"<svg viewBox="{} {} {} {}" xmlns="http://www.w3.org/2000/svg">
+  <path d="{}" fill="cyan" stroke="none" />
+  {}
+</svg>"#,
+        viewbox_x, viewbox_y, viewbox_width, viewbox_height, path, extra
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("1,2\n3,2"), Ok(vec![(1, 2), (3, 2)]));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("1,2\n3;4"),
+            Err(ParseError::new(9, 2, 1, "3;4", "invalid line"))
+        );
+        assert_eq!(
+            parse("1,2\n3,-4"),
+            Err(ParseError::new(9, 2, 3, "-4", "invalid number"))
+        );
+        assert_eq!(
+            parse("1,1\n5,5"),
+            Err(ParseError::new(
+                9,
+                2,
+                1,
+                "5,5",
+                "not in line with the previous point"
+            ))
+        );
+        assert_eq!(
+            parse("1,1\n1,5\n5,5"),
+            Err(ParseError::new(
+                9,
+                3,
+                1,
+                "5,5",
+                "not in line with the first point"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        common::examples::assert_example::<Day9>(
+            include_str!("../examples/example.txt"),
+            include_str!("../examples/example.expected"),
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        // A loop that a rectangle of corners over 40 tiles would leave.
+        let input = "21,15\n21,11\n25,11\n25,9\n21,9\n21,7\n20,7\n20,5\n17,5\n17,3\n15,3\n15,2\n11,2\n11,1\n7,1\n7,2\n4,2\n4,3\n0,3\n0,7\n7,7\n7,3\n11,3\n11,7\n15,7\n15,10\n17,10\n17,11\n20,11\n20,15";
+        assert_eq!(part2(&parse(input).unwrap()), 40);
+    }
+}
@@ -0,0 +1,87 @@
+use common::Reference;
+
+use crate::{Day9, area, parse};
+
+impl Reference for Day9 {
+    fn naive1(input: &str, _: &()) -> u64 {
+        let points = parse(input).unwrap();
+        let mut max = 0;
+        for &p1 in &points {
+            for &p2 in &points {
+                max = max.max(area(p1, p2));
+            }
+        }
+        max
+    }
+
+    fn naive2(input: &str, _: &()) -> u64 {
+        let points = parse(input).unwrap();
+        let mut max = 0;
+        for &p1 in &points {
+            for &p2 in &points {
+                let (cols, rows) = (
+                    p1.0.min(p2.0)..=p1.0.max(p2.0),
+                    p1.1.min(p2.1)..=p1.1.max(p2.1),
+                );
+                let inside = cols
+                    .clone()
+                    .all(|x| rows.clone().all(|y| is_inside(&points, (x, y))));
+                if inside {
+                    max = max.max(area(p1, p2));
+                }
+            }
+        }
+        max
+    }
+
+    fn is_valid(input: &str, _: &()) -> bool {
+        let points = parse(input).unwrap();
+        let n = points.len();
+        n >= 4
+            && (0..n).all(|i| {
+                let (p1, p2, p3) = (points[i], points[(i + 1) % n], points[(i + 2) % n]);
+                let straight = (p1.0 == p2.0 && p2.0 == p3.0) || (p1.1 == p2.1 && p2.1 == p3.1);
+                p1 != p2 && (p1.0 == p2.0 || p1.1 == p2.1) && !straight
+            })
+            && !crosses(&points)
+    }
+}
+
+fn edges(points: &[(u64, u64)]) -> impl Iterator<Item = ((u64, u64), (u64, u64))> + '_ {
+    (0..points.len()).map(|i| (points[i], points[(i + 1) % points.len()]))
+}
+
+fn on_edge(p: (u64, u64), (a, b): ((u64, u64), (u64, u64))) -> bool {
+    (a.0.min(b.0)..=a.0.max(b.0)).contains(&p.0) && (a.1.min(b.1)..=a.1.max(b.1)).contains(&p.1)
+}
+
+/// Whether the tile is on the loop or enclosed by it, casting a ray to the
+/// right through the tile's centre.
+fn is_inside(points: &[(u64, u64)], p: (u64, u64)) -> bool {
+    if edges(points).any(|edge| on_edge(p, edge)) {
+        return true;
+    }
+    let crossings = edges(points)
+        .filter(|&(a, b)| a.0 == b.0 && a.0 > p.0 && a.1.min(b.1) <= p.1 && p.1 < a.1.max(b.1))
+        .count();
+    crossings % 2 == 1
+}
+
+/// Whether any two edges that aren't neighbours touch.
+fn crosses(points: &[(u64, u64)]) -> bool {
+    let n = points.len();
+    let edges: Vec<_> = edges(points).collect();
+    (0..n).any(|i| {
+        (i + 2..n).any(|j| {
+            if i == 0 && j == n - 1 {
+                return false;
+            }
+            let (a, b) = (edges[i], edges[j]);
+            let tiles = |(p, q): ((u64, u64), (u64, u64))| {
+                (p.0.min(q.0)..=p.0.max(q.0))
+                    .flat_map(move |x| (p.1.min(q.1)..=p.1.max(q.1)).map(move |y| (x, y)))
+            };
+            tiles(a).any(|t| on_edge(t, b))
+        })
+    })
+}
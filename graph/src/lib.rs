@@ -0,0 +1,338 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+    ops::Index,
+};
+
+/// Index of a node in its [`Graph`], in the order the nodes were added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A directed graph whose nodes are named by `N`, interned to [`NodeId`]s,
+/// keeping the edges in both directions.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    names: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    successors: Vec<Vec<NodeId>>,
+    predecessors: Vec<Vec<NodeId>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            successors: Vec::new(),
+            predecessors: Vec::new(),
+        }
+    }
+}
+
+/// Equal when the nodes and edges were added in the same order, the rest
+/// follows from those.
+impl<N: PartialEq> PartialEq for Graph<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.names == other.names && self.successors == other.successors
+    }
+}
+
+impl<N: Eq> Eq for Graph<N> {}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id of the node named `name`, adding it first if it's new.
+    pub fn add_node(&mut self, name: N) -> NodeId {
+        if let Some(&id) = self.ids.get(&name) {
+            return id;
+        }
+        let id = NodeId(self.names.len());
+        self.names.push(name.clone());
+        self.ids.insert(name, id);
+        self.successors.push(Vec::new());
+        self.predecessors.push(Vec::new());
+        id
+    }
+
+    /// Adds an edge from `from` to `to`, unless it's already there.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId) {
+        if !self.successors[from.0].contains(&to) {
+            self.successors[from.0].push(to);
+            self.predecessors[to.0].push(from);
+        }
+    }
+
+    pub fn id(&self, name: &N) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    /// The graph with only the nodes for which `keep` is true and the edges
+    /// between them. Nodes keep their relative order but get new ids.
+    pub fn subgraph(&self, mut keep: impl FnMut(NodeId) -> bool) -> Self {
+        let mut res = Self::new();
+        let ids: Vec<Option<NodeId>> = self
+            .nodes()
+            .map(|id| keep(id).then(|| res.add_node(self[id].clone())))
+            .collect();
+        for (from, to) in self.edges() {
+            if let (Some(from), Some(to)) = (ids[from.0], ids[to.0]) {
+                res.add_edge(from, to);
+            }
+        }
+        res
+    }
+}
+
+impl<N> Graph<N> {
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> + use<N> {
+        (0..self.len()).map(NodeId)
+    }
+
+    /// Every edge as `(from, to)`.
+    pub fn edges(&self) -> impl Iterator<Item = (NodeId, NodeId)> {
+        self.nodes()
+            .flat_map(|from| self.successors(from).iter().map(move |&to| (from, to)))
+    }
+
+    /// Nodes with an edge from `id`, in the order the edges were added.
+    pub fn successors(&self, id: NodeId) -> &[NodeId] {
+        &self.successors[id.0]
+    }
+
+    /// Nodes with an edge to `id`, in the order the edges were added.
+    pub fn predecessors(&self, id: NodeId) -> &[NodeId] {
+        &self.predecessors[id.0]
+    }
+
+    /// Nodes reachable from `start`, itself included, in breadth-first order.
+    pub fn bfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut seen = vec![false; self.len()];
+        let mut queue = VecDeque::from([start]);
+        let mut res = Vec::new();
+        seen[start.0] = true;
+        while let Some(id) = queue.pop_front() {
+            res.push(id);
+            for &next in self.successors(id) {
+                if !seen[next.0] {
+                    seen[next.0] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        res
+    }
+
+    /// Nodes reachable from `start`, itself included, in depth-first preorder.
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut seen = vec![false; self.len()];
+        let mut stack = vec![start];
+        let mut res = Vec::new();
+        while let Some(id) = stack.pop() {
+            if seen[id.0] {
+                continue;
+            }
+            seen[id.0] = true;
+            res.push(id);
+            // Reversed so the first successor is visited first.
+            stack.extend(
+                self.successors(id)
+                    .iter()
+                    .rev()
+                    .filter(|next| !seen[next.0]),
+            );
+        }
+        res
+    }
+
+    pub fn is_reachable(&self, from: NodeId, to: NodeId) -> bool {
+        self.bfs(from).contains(&to)
+    }
+
+    /// Nodes along a shortest path from `start` to `end`, both included.
+    pub fn shortest_path(&self, start: NodeId, end: NodeId) -> Option<Vec<NodeId>> {
+        let mut parent: Vec<Option<NodeId>> = vec![None; self.len()];
+        let mut queue = VecDeque::from([start]);
+        parent[start.0] = Some(start);
+        while let Some(id) = queue.pop_front() {
+            if id == end {
+                let mut path = vec![end];
+                let mut id = end;
+                while id != start {
+                    id = parent[id.0]?;
+                    path.push(id);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for &next in self.successors(id) {
+                if parent[next.0].is_none() {
+                    parent[next.0] = Some(id);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Every node ordered so edges only point forward, or `None` if there is
+    /// a cycle.
+    pub fn toposort(&self) -> Option<Vec<NodeId>> {
+        let mut inputs: Vec<usize> = self.predecessors.iter().map(Vec::len).collect();
+        let mut queue: VecDeque<NodeId> = self.nodes().filter(|id| inputs[id.0] == 0).collect();
+        let mut res = Vec::new();
+        while let Some(id) = queue.pop_front() {
+            res.push(id);
+            for &next in self.successors(id) {
+                inputs[next.0] -= 1;
+                if inputs[next.0] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+        (res.len() == self.len()).then_some(res)
+    }
+
+    /// The nodes of some cycle in order, each with an edge to the next and
+    /// the last with one to the first, or `None` if the graph is acyclic.
+    pub fn find_cycle(&self) -> Option<Vec<NodeId>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            New,
+            OnPath,
+            Done,
+        }
+
+        let mut state = vec![State::New; self.len()];
+        for root in self.nodes() {
+            if state[root.0] != State::New {
+                continue;
+            }
+            // The current path, with how many successors of each are visited.
+            let mut path = vec![(root, 0)];
+            state[root.0] = State::OnPath;
+            while let Some(&mut (id, ref mut visited)) = path.last_mut() {
+                let Some(&next) = self.successors(id).get(*visited) else {
+                    state[id.0] = State::Done;
+                    path.pop();
+                    continue;
+                };
+                *visited += 1;
+                match state[next.0] {
+                    State::New => {
+                        state[next.0] = State::OnPath;
+                        path.push((next, 0));
+                    }
+                    State::OnPath => {
+                        let start = path.iter().position(|&(id, _)| id == next)?;
+                        return Some(path[start..].iter().map(|&(id, _)| id).collect());
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+        None
+    }
+
+    pub fn is_acyclic(&self) -> bool {
+        self.find_cycle().is_none()
+    }
+}
+
+impl<N> Index<NodeId> for Graph<N> {
+    type Output = N;
+
+    /// The name of a node.
+    fn index(&self, id: NodeId) -> &N {
+        &self.names[id.0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a graph from `from-to` pairs.
+    fn graph(edges: &str) -> Graph<char> {
+        let mut graph = Graph::new();
+        for edge in edges.split_whitespace() {
+            let (from, to) = edge.split_once('-').unwrap();
+            let from = graph.add_node(from.parse().unwrap());
+            let to = graph.add_node(to.parse().unwrap());
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    fn names(graph: &Graph<char>, ids: &[NodeId]) -> String {
+        ids.iter().map(|&id| graph[id]).collect()
+    }
+
+    fn id(graph: &Graph<char>, name: char) -> NodeId {
+        graph.id(&name).unwrap()
+    }
+
+    #[test]
+    fn test_interning() {
+        let mut g = graph("a-b a-c c-b a-b");
+        assert_eq!(g.len(), 3);
+        assert_eq!(g.add_node('c'), id(&g, 'c'));
+        assert_eq!(g.id(&'d'), None);
+        assert_eq!(names(&g, g.successors(id(&g, 'a'))), "bc");
+        assert_eq!(names(&g, g.predecessors(id(&g, 'b'))), "ac");
+        assert_eq!(g.edges().count(), 3);
+    }
+
+    #[test]
+    fn test_search() {
+        let g = graph("a-b a-c b-d c-e e-d d-f x-a");
+        let a = id(&g, 'a');
+        assert_eq!(names(&g, &g.bfs(a)), "abcdef");
+        assert_eq!(names(&g, &g.dfs(a)), "abdfce");
+        assert!(g.is_reachable(a, id(&g, 'f')));
+        assert!(!g.is_reachable(a, id(&g, 'x')));
+        assert_eq!(names(&g, &g.shortest_path(a, id(&g, 'f')).unwrap()), "abdf");
+        assert_eq!(g.shortest_path(a, id(&g, 'x')), None);
+        assert_eq!(names(&g, &g.shortest_path(a, a).unwrap()), "a");
+    }
+
+    #[test]
+    fn test_toposort() {
+        let g = graph("a-b b-c a-c d-a");
+        assert_eq!(names(&g, &g.toposort().unwrap()), "dabc");
+        assert_eq!(g.find_cycle(), None);
+        assert!(g.is_acyclic());
+
+        let g = graph("a-b b-c c-d d-b");
+        assert_eq!(g.toposort(), None);
+        assert_eq!(names(&g, &g.find_cycle().unwrap()), "bcd");
+
+        let g = graph("a-a");
+        assert_eq!(names(&g, &g.find_cycle().unwrap()), "a");
+    }
+
+    #[test]
+    fn test_subgraph() {
+        let g = graph("a-b b-c c-d a-d");
+        let sub = g.subgraph(|id| g[id] != 'b');
+        assert_eq!(names(&sub, &sub.nodes().collect::<Vec<_>>()), "acd");
+        assert_eq!(names(&sub, sub.successors(id(&sub, 'a'))), "d");
+        assert_eq!(names(&sub, sub.predecessors(id(&sub, 'd'))), "ac");
+    }
+}
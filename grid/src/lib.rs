@@ -0,0 +1,365 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use common::ParseError;
+
+/// A cell of a grid, counted from the top left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Self; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The four directions along the axes, clockwise from north.
+    pub const ORTHOGONAL: [Self; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Change of row and column for one step.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::NorthEast => (-1, 1),
+            Direction::East => (0, 1),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (1, 0),
+            Direction::SouthWest => (1, -1),
+            Direction::West => (0, -1),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+}
+
+/// A rectangular grid stored row by row in one vector.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `rows` by `cols` grid with every cell set to `fill`.
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// `None` if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+        Some(Self {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one cell per character and one row per line. `cell` returns
+    /// `None` for characters that aren't cells.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut cols = 0;
+
+        for line in common::lines(day, input) {
+            let start = cells.len();
+            for (i, c) in line.text.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        return Err(line.error(&line.text[i..i + c.len_utf8()], "invalid cell"));
+                    }
+                }
+            }
+
+            let len = cells.len() - start;
+            if rows == 0 {
+                cols = len;
+            } else if len != cols {
+                let message = format!("expected {} cells, got {}", cols, len);
+                return Err(line.error(line.text, message));
+            }
+            rows += 1;
+        }
+
+        Ok(Self { cells, rows, cols })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.row < self.rows && pos.col < self.cols
+    }
+
+    /// The cell at `pos`, if inside the grid.
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.index(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if self.contains(pos) {
+            let i = self.index(pos);
+            Some(&mut self.cells[i])
+        } else {
+            None
+        }
+    }
+
+    /// Sets the cell at `pos`, doing nothing outside the grid.
+    pub fn set(&mut self, pos: Position, value: T) {
+        if let Some(cell) = self.get_mut(pos) {
+            *cell = value;
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column {} out of {}", col, self.cols);
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    /// The rows from top to bottom.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows).map(|row| self.row(row))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| Position::new(row, col)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position one step from `pos`, if still inside the grid.
+    pub fn step(&self, pos: Position, dir: Direction) -> Option<Position> {
+        let (dr, dc) = dir.delta();
+        let next = Position::new(
+            pos.row.checked_add_signed(dr)?,
+            pos.col.checked_add_signed(dc)?,
+        );
+        self.contains(next).then_some(next)
+    }
+
+    /// The position one step from `pos`, coming back in on the opposite edge
+    /// when leaving the grid.
+    pub fn step_wrapping(&self, pos: Position, dir: Direction) -> Position {
+        let (dr, dc) = dir.delta();
+        Position::new(
+            (pos.row + self.rows).wrapping_add_signed(dr) % self.rows,
+            (pos.col + self.cols).wrapping_add_signed(dc) % self.cols,
+        )
+    }
+
+    /// The up to four orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The up to eight neighbours of `pos` inside the grid, diagonals included.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Position of the first cell, row by row, matching `pred`.
+    pub fn find(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find_map(|(pos, cell)| pred(cell).then_some(pos))
+    }
+
+    fn index(&self, pos: Position) -> usize {
+        pos.row * self.cols + pos.col
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} outside {}x{} grid", pos, self.rows, self.cols))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} outside {}x{} grid", pos, rows, cols))
+    }
+}
+
+/// One line per row, so grids of characters print back as the text they were
+/// parsed from.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.iter_rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter_rows()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell(c: char) -> Option<char> {
+        matches!(c, '.' | '#').then_some(c)
+    }
+
+    fn grid() -> Grid<char> {
+        Grid::parse(0, "#..\n.#.", cell).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(
+            Some(grid),
+            Grid::from_rows(vec![vec!['#', '.', '.'], vec!['.', '#', '.']])
+        );
+        assert_eq!(Grid::<char>::from_rows(vec![vec!['#'], vec![]]), None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Grid::parse(3, "#..\n.x.", cell),
+            Err(ParseError::new(3, 2, 2, "x", "invalid cell"))
+        );
+        assert_eq!(
+            Grid::parse(3, "#..\n.#", cell),
+            Err(ParseError::new(3, 2, 1, ".#", "expected 3 cells, got 2"))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let text = "#..\n.#.\n";
+        let grid = Grid::parse(0, text, cell).unwrap();
+        assert_eq!(grid.to_string(), text);
+        assert_eq!(Grid::parse(0, &grid.to_string(), cell), Ok(grid));
+    }
+
+    #[test]
+    fn test_slices() {
+        let grid = grid();
+        assert_eq!(grid.row(1), ['.', '#', '.']);
+        assert_eq!(grid.column(0).collect::<String>(), "#.");
+        assert_eq!(grid.column(2).collect::<String>(), "..");
+        assert_eq!(grid.find(|&c| c == '#'), Some(Position::new(0, 0)));
+        assert_eq!(grid[Position::new(1, 1)], '#');
+        assert_eq!(grid.get(Position::new(2, 0)), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        let corner = Position::new(0, 0);
+        let centre = Position::new(1, 1);
+        assert_eq!(grid.neighbours4(corner).count(), 2);
+        assert_eq!(grid.neighbours8(corner).count(), 3);
+        assert_eq!(grid.neighbours4(centre).count(), 4);
+        assert_eq!(grid.neighbours8(centre).count(), 8);
+    }
+
+    #[test]
+    fn test_step() {
+        let grid = grid();
+        let corner = Position::new(0, 2);
+        assert_eq!(grid.step(corner, Direction::NorthEast), None);
+        assert_eq!(
+            grid.step(corner, Direction::SouthWest),
+            Some(Position::new(1, 1))
+        );
+        assert_eq!(
+            grid.step_wrapping(corner, Direction::NorthEast),
+            Position::new(1, 0)
+        );
+        assert_eq!(
+            grid.step_wrapping(Position::new(1, 0), Direction::West),
+            Position::new(1, 2)
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use std::iter;
+
+/// A set of integers stored as sorted, disjoint inclusive ranges, with
+/// touching ranges merged so every set has one representation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<(u64, u64)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value from `min` to `max` inclusive. Empty ranges, where
+    /// `min > max`, are ignored.
+    pub fn insert(&mut self, (min, max): (u64, u64)) {
+        if min > max {
+            return;
+        }
+        // Ranges overlapping or touching the new one, which it absorbs.
+        let start = self.ranges.partition_point(|r| r.1.saturating_add(1) < min);
+        let end = self
+            .ranges
+            .partition_point(|r| r.0 <= max.saturating_add(1));
+        let merged = match self.ranges[start..end] {
+            [] => (min, max),
+            [first, .., last] | [first @ last] => (min.min(first.0), max.max(last.1)),
+        };
+        self.ranges.splice(start..end, [merged]);
+    }
+
+    /// Removes every value from `min` to `max` inclusive.
+    pub fn remove(&mut self, (min, max): (u64, u64)) {
+        if min > max {
+            return;
+        }
+        let start = self.ranges.partition_point(|r| r.1 < min);
+        let end = self.ranges.partition_point(|r| r.0 <= max);
+        let mut rest = Vec::new();
+        if let [first, .., last] | [first @ last] = self.ranges[start..end] {
+            if first.0 < min {
+                rest.push((first.0, min - 1));
+            }
+            if last.1 > max {
+                rest.push((max + 1, last.1));
+            }
+        }
+        self.ranges.splice(start..end, rest);
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        let i = self.ranges.partition_point(|r| r.1 < value);
+        self.ranges.get(i).is_some_and(|r| r.0 <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of values in the set. Overflows for a set of every `u64`.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|r| r.1 - r.0 + 1).sum()
+    }
+
+    /// The ranges in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, u64)> {
+        self.ranges.iter().copied()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut res = self.clone();
+        for range in other.iter() {
+            res.insert(range);
+        }
+        res
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(&&x), Some(&&y)) = (a.peek(), b.peek()) {
+            let (min, max) = (x.0.max(y.0), x.1.min(y.1));
+            if min <= max {
+                ranges.push((min, max));
+            }
+            // Drop whichever ends first, the other may overlap more ranges.
+            if x.1 < y.1 {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut res = self.clone();
+        for range in other.iter() {
+            res.remove(range);
+        }
+        res
+    }
+
+    /// Every value from `min` to `max` inclusive that isn't in the set.
+    pub fn complement(&self, bounds: (u64, u64)) -> Self {
+        Self {
+            ranges: self.gaps(bounds).collect(),
+        }
+    }
+
+    /// The maximal ranges between `min` and `max` inclusive not covered by
+    /// the set, in ascending order.
+    pub fn gaps(&self, (min, max): (u64, u64)) -> impl Iterator<Item = (u64, u64)> {
+        let start = self.ranges.partition_point(|r| r.1 < min);
+        let mut ranges = self.ranges[start..].iter();
+        // Smallest value not yet known to be covered, `None` past `u64::MAX`.
+        let mut next = Some(min);
+        iter::from_fn(move || {
+            loop {
+                let from = next.filter(|&from| from <= max)?;
+                match ranges.next() {
+                    Some(&(lo, hi)) if lo <= max => {
+                        next = hi.checked_add(1);
+                        if lo > from {
+                            return Some((from, lo - 1));
+                        }
+                    }
+                    _ => {
+                        next = None;
+                        return Some((from, max));
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl FromIterator<(u64, u64)> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = (u64, u64)>>(iter: I) -> Self {
+        let mut res = Self::new();
+        for range in iter {
+            res.insert(range);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(u64, u64)]) -> IntervalSet {
+        ranges.iter().copied().collect()
+    }
+
+    fn ranges(set: &IntervalSet) -> Vec<(u64, u64)> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        assert_eq!(ranges(&set(&[(1, 3), (3, 6)])), [(1, 6)]);
+        assert_eq!(ranges(&set(&[(1, 2), (4, 6)])), [(1, 2), (4, 6)]);
+        assert_eq!(ranges(&set(&[(1, 2), (3, 6)])), [(1, 6)]);
+        assert_eq!(ranges(&set(&[(2, 6), (1, 4)])), [(1, 6)]);
+        assert_eq!(
+            ranges(&set(&[(1, 2), (8, 9), (5, 5), (0, 6)])),
+            [(0, 6), (8, 9)]
+        );
+        assert_eq!(ranges(&set(&[(5, 1)])), []);
+        assert_eq!(
+            ranges(&set(&[(u64::MAX, u64::MAX), (0, u64::MAX - 1)])),
+            [(0, u64::MAX)]
+        );
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut s = set(&[(1, 10), (20, 30)]);
+        s.remove((5, 22));
+        assert_eq!(ranges(&s), [(1, 4), (23, 30)]);
+        s.remove((2, 3));
+        assert_eq!(ranges(&s), [(1, 1), (4, 4), (23, 30)]);
+        s.remove((0, 100));
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_contains() {
+        let s = set(&[(1, 4), (8, 8)]);
+        assert!(s.contains(1));
+        assert!(s.contains(4));
+        assert!(s.contains(8));
+        assert!(!s.contains(0));
+        assert!(!s.contains(5));
+        assert!(!s.contains(9));
+        assert_eq!(s.len(), 5);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (20, 20)]);
+        assert_eq!(ranges(&a.union(&b)), [(1, 15), (20, 20)]);
+        assert_eq!(ranges(&a.intersection(&b)), [(4, 5), (10, 11)]);
+        assert_eq!(ranges(&a.difference(&b)), [(1, 3), (12, 15)]);
+        assert_eq!(ranges(&b.difference(&a)), [(6, 9), (20, 20)]);
+    }
+
+    #[test]
+    fn test_gaps() {
+        let s = set(&[(3, 5), (10, 20)]);
+        assert_eq!(
+            s.gaps((0, 30)).collect::<Vec<_>>(),
+            [(0, 2), (6, 9), (21, 30)]
+        );
+        assert_eq!(s.gaps((4, 12)).collect::<Vec<_>>(), [(6, 9)]);
+        assert_eq!(s.gaps((11, 15)).count(), 0);
+        assert_eq!(ranges(&s.complement((0, 10))), [(0, 2), (6, 9)]);
+        assert_eq!(
+            ranges(&set(&[(0, 1)]).complement((0, u64::MAX))),
+            [(2, u64::MAX)]
+        );
+        assert_eq!(
+            set(&[(5, u64::MAX)])
+                .gaps((0, u64::MAX))
+                .collect::<Vec<_>>(),
+            [(0, 4)]
+        );
+    }
+}
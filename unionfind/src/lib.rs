@@ -0,0 +1,144 @@
+/// Disjoint sets over the elements `0..len`, merged with [`UnionFind::union`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnionFind {
+    /// Parent of each element, roots being their own parent.
+    parent: Vec<usize>,
+    /// Number of elements under each root, stale for the other elements.
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    /// `len` elements, each in a set of its own.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            count: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of the set containing `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Point the whole way at the root, so the next find is quick.
+        let mut x = x;
+        while self.parent[x] != root {
+            x = std::mem::replace(&mut self.parent[x], root);
+        }
+        root
+    }
+
+    /// Merges the sets of `a` and `b`, returning false if they were one set
+    /// already.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        // Hang the smaller tree under the larger, keeping the trees shallow.
+        let (small, large) = if self.size[a] < self.size[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[small] = large;
+        self.size[large] += self.size[small];
+        self.count -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set containing `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Number of sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The size of every set, in no particular order.
+    pub fn sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|root| self.size[root])
+            .collect()
+    }
+
+    /// The elements of every set in ascending order, the sets ordered by
+    /// their smallest elements.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut res: Vec<Vec<usize>> = Vec::new();
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if index[root] == usize::MAX {
+                index[root] = res.len();
+                res.push(Vec::new());
+            }
+            res[index[root]].push(x);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.count(), 6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.same(0, 3));
+        assert!(!sets.same(0, 4));
+        assert_eq!(sets.count(), 3);
+        assert_eq!(sets.size(2), 4);
+        assert_eq!(sets.size(5), 1);
+    }
+
+    #[test]
+    fn test_components() {
+        let mut sets = UnionFind::new(5);
+        sets.union(4, 1);
+        sets.union(3, 0);
+        sets.union(1, 3);
+        assert_eq!(sets.components(), vec![vec![0, 1, 3, 4], vec![2]]);
+        let mut sizes = sets.sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 4]);
+        assert!(UnionFind::new(0).components().is_empty());
+    }
+
+    #[test]
+    fn test_path_compression() {
+        let mut sets = UnionFind::new(4);
+        // Equal sizes keep the first root, building the chain 3 -> 2 -> 0.
+        sets.union(0, 1);
+        sets.union(2, 3);
+        sets.union(0, 2);
+        let root = sets.find(3);
+        assert_eq!(sets.parent[3], root);
+    }
+}